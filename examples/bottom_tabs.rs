@@ -1,3 +1,4 @@
+#![allow(clippy::enum_variant_names, clippy::ptr_arg)]
 mod app {
     use iced::{
         Alignment, Element, Length, Task, Theme,
@@ -6,7 +7,7 @@ mod app {
     use iced_font_awesome::fa_icon_solid;
     use iced_navigation::{
         operations::navigate,
-        tabs_navigator::{Mode, PageParams, tabs_navigator},
+        tabs_navigator::{Mode, PageParams, Tab, tabs_navigator},
     };
 
     #[derive(Debug, Clone, Copy)]
@@ -158,8 +159,13 @@ mod app {
         .into()
     }
 
-    fn tabs<'a>(params: PageParams<Page>, pages: &Vec<Page>) -> Element<'a, Message> {
-        container(pages.iter().fold(Row::new(), |row, page| {
+    fn tabs<'a>(params: PageParams<Page>, pages: &Vec<Tab<Page>>) -> Element<'a, Message> {
+        container(pages.iter().fold(Row::new(), |row, tab| {
+            if !tab.visible {
+                return row;
+            }
+
+            let page = &tab.key;
             let selected = *page == params.current_page;
 
             row.push(
@@ -180,10 +186,10 @@ mod app {
                     .padding(5)
                     .align_x(Alignment::Center),
                 )
-                .on_press_maybe(if selected {
+                .on_press_maybe(if selected || !tab.enabled {
                     None
                 } else {
-                    Some(Message::Navigate(page.clone()))
+                    Some(Message::Navigate(*page))
                 }),
             )
         }))
@@ -1,3 +1,4 @@
+#![allow(clippy::enum_variant_names, clippy::ptr_arg)]
 mod app {
     use iced::{
         Alignment, Element, Length, Task, Theme,
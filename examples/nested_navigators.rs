@@ -1,3 +1,4 @@
+#![allow(clippy::enum_variant_names, clippy::ptr_arg)]
 mod app {
     use iced::{
         Alignment, Element, Length, Task, Theme,
@@ -7,7 +8,7 @@ mod app {
     use iced_navigation::{
         operations::navigate_by_id,
         stack_navigator::stack_navigator,
-        tabs_navigator::{Mode, PageParams, tabs_navigator},
+        tabs_navigator::{Mode, PageParams, Tab, tabs_navigator},
     };
 
     #[derive(Debug, Clone)]
@@ -231,8 +232,13 @@ mod app {
         .into()
     }
 
-    fn tabs<'a>(params: PageParams<TabsPage>, pages: &Vec<TabsPage>) -> Element<'a, Message> {
-        container(pages.iter().fold(Row::new(), |row, page| {
+    fn tabs<'a>(params: PageParams<TabsPage>, pages: &Vec<Tab<TabsPage>>) -> Element<'a, Message> {
+        container(pages.iter().fold(Row::new(), |row, tab| {
+            if !tab.visible {
+                return row;
+            }
+
+            let page = &tab.key;
             let selected = *page == params.current_page;
 
             row.push(
@@ -253,10 +259,10 @@ mod app {
                     .padding(5)
                     .align_x(Alignment::Center),
                 )
-                .on_press_maybe(if selected {
+                .on_press_maybe(if selected || !tab.enabled {
                     None
                 } else {
-                    Some(Message::Navigate(Page::TabsPage(page.clone())))
+                    Some(Message::Navigate(Page::TabsPage(*page)))
                 }),
             )
         }))
@@ -6,7 +6,7 @@ use iced::{
 use iced_font_awesome::fa_icon_solid;
 use iced_navigation::{
     operations::{clear_history, go_back, navigate},
-    stack_navigator::{PageParams, stack_navigator},
+    stack_navigator::{PageHeader, PageOptions, PageParams, stack_navigator},
 };
 
 #[derive(Debug, Clone)]
@@ -18,6 +18,7 @@ enum Message {
     GoBack,
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
 enum Page {
     LoginPage,
@@ -25,22 +26,13 @@ enum Page {
     Details(u32),
 }
 
+#[derive(Default)]
 struct App {
     username: String,
     password: String,
     error: Option<String>,
 }
 
-impl Default for App {
-    fn default() -> Self {
-        App {
-            password: String::new(),
-            username: String::new(),
-            error: None,
-        }
-    }
-}
-
 impl App {
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
@@ -61,7 +53,7 @@ impl App {
 
                 self.error = None;
 
-                return navigate(Page::HomePage(username));
+                navigate(Page::HomePage(username))
             }
             Message::Navigate(page) => navigate(page),
             Message::GoBack => go_back::<Message, Page>(),
@@ -70,7 +62,7 @@ impl App {
                     return clear_history::<Message, Page>();
                 }
 
-                return Task::none();
+                Task::none()
             }
         }
     }
@@ -78,9 +70,12 @@ impl App {
     fn view(&self) -> Element<Message> {
         stack_navigator(Page::LoginPage)
             .header_widget(header)
-            .insert_page(
+            .insert_page_with_options(
                 Page::LoginPage,
                 login_home(&self.username, &self.password, self.error.as_ref()),
+                PageOptions {
+                    header: PageHeader::Hidden,
+                },
             )
             .insert_page_with(Page::HomePage(String::new()), home_page)
             .insert_page_with(Page::Details(0), details_page)
@@ -223,7 +218,7 @@ fn header<'a>(params: PageParams<Page>) -> Element<'a, Message> {
     let title = match params.page {
         Page::HomePage(_) => "Home page".to_owned(),
         Page::Details(id) => format!("Details number {id}"),
-        Page::LoginPage => return None::<Option<Element<_>>>.into(),
+        Page::LoginPage => "Login".to_owned(),
     };
 
     container(
@@ -1,16 +1,266 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
 use iced_core::time::{Duration, Instant};
 
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
 const DEFAULT_DURATION: f32 = 0.3;
 
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+pub type Easing = fn(f32) -> f32;
+
+pub fn set_reduced_motion(reduced: bool) {
+    REDUCED_MOTION.store(reduced, Ordering::Relaxed);
+}
+
+pub fn reduced_motion() -> bool {
+    REDUCED_MOTION.load(Ordering::Relaxed)
+}
+
+pub mod easing {
+    pub fn linear(progress: f32) -> f32 {
+        progress
+    }
+
+    pub fn ease_in_out_cubic(progress: f32) -> f32 {
+        if progress < 0.5 {
+            4.0 * progress.powi(3)
+        } else {
+            1.0 - (-2.0 * progress + 2.0).powi(3) / 2.0
+        }
+    }
+
+    pub fn material_standard(progress: f32) -> f32 {
+        cubic_bezier(0.4, 0.0, 0.2, 1.0, progress)
+    }
+
+    pub fn ios(progress: f32) -> f32 {
+        cubic_bezier(0.25, 0.1, 0.25, 1.0, progress)
+    }
+
+    fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, progress: f32) -> f32 {
+        let sample = |a: f32, b: f32, t: f32| {
+            3.0 * a * (1.0 - t).powi(2) * t + 3.0 * b * (1.0 - t) * t.powi(2) + t.powi(3)
+        };
+
+        let progress = progress.clamp(0.0, 1.0);
+        let (mut low, mut high) = (0.0, 1.0);
+        let mut t = progress;
+
+        for _ in 0..24 {
+            let x = sample(x1, x2, t);
+
+            if (x - progress).abs() < 1e-5 {
+                break;
+            }
+
+            if x < progress {
+                low = t;
+            } else {
+                high = t;
+            }
+
+            t = (low + high) / 2.0;
+        }
+
+        sample(y1, y2, t)
+    }
+}
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
+pub(crate) trait Animation {
+    fn update(&mut self);
+
+    fn is_complete(&self) -> bool;
+
+    fn get_value(&self) -> f32;
+
+    #[cfg(any(feature = "stack", feature = "drawer"))]
+    fn reverse(&mut self);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    pub stiffness: f32,
+    pub damping: f32,
+    pub mass: f32,
+}
+
+impl Spring {
+    pub fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass,
+        }
+    }
+
+    #[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
+    fn is_valid(&self) -> bool {
+        self.stiffness > 0.0 && self.damping >= 0.0 && self.mass > 0.0
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::new(170.0, 26.0, 1.0)
+    }
+}
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
+#[derive(Debug, Clone)]
+pub(crate) enum Animator {
+    Timed(Frame),
+    Spring(SpringFrame),
+}
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
+impl Animation for Animator {
+    fn update(&mut self) {
+        match self {
+            Self::Timed(frame) => frame.update(),
+            Self::Spring(frame) => frame.update(),
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        match self {
+            Self::Timed(frame) => frame.is_complete(),
+            Self::Spring(frame) => frame.is_complete(),
+        }
+    }
+
+    fn get_value(&self) -> f32 {
+        match self {
+            Self::Timed(frame) => frame.get_value(),
+            Self::Spring(frame) => frame.get_value(),
+        }
+    }
+
+    #[cfg(any(feature = "stack", feature = "drawer"))]
+    fn reverse(&mut self) {
+        match self {
+            Self::Timed(frame) => frame.reverse(),
+            Self::Spring(frame) => frame.reverse(),
+        }
+    }
+}
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
+impl From<Frame> for Animator {
+    fn from(frame: Frame) -> Self {
+        Self::Timed(frame)
+    }
+}
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
+impl From<SpringFrame> for Animator {
+    fn from(frame: SpringFrame) -> Self {
+        Self::Spring(frame)
+    }
+}
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
+#[derive(Debug, Clone)]
+pub(crate) struct SpringFrame {
+    spring: Spring,
+    position: f32,
+    velocity: f32,
+    last: Instant,
+    complete: bool,
+}
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
+impl SpringFrame {
+    const MAX_STEP: f32 = 1.0 / 120.0;
+    const REST: f32 = 1e-3;
+
+    pub fn new(spring: Spring) -> Self {
+        Self {
+            spring,
+            position: 0.0,
+            velocity: 0.0,
+            last: Instant::now(),
+            complete: false,
+        }
+    }
+
+    #[cfg(feature = "drawer")]
+    pub fn velocity(mut self, velocity: f32) -> Self {
+        self.velocity = velocity;
+
+        self
+    }
+
+    fn at_rest(&self) -> bool {
+        (1.0 - self.position).abs() < Self::REST && self.velocity.abs() < Self::REST
+    }
+}
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
+impl Animation for SpringFrame {
+    fn update(&mut self) {
+        let now = Instant::now();
+        let mut elapsed = (now - self.last).as_secs_f32();
+
+        self.last = now;
+
+        if reduced_motion() || !self.spring.is_valid() {
+            self.position = 1.0;
+            self.velocity = 0.0;
+        }
+
+        while elapsed > 0.0 && !self.at_rest() {
+            let step = elapsed.min(Self::MAX_STEP);
+            let force = -self.spring.stiffness * (self.position - 1.0)
+                - self.spring.damping * self.velocity;
+
+            self.velocity += force / self.spring.mass * step;
+            self.position += self.velocity * step;
+
+            elapsed -= step;
+        }
+
+        if self.at_rest() {
+            self.position = 1.0;
+            self.velocity = 0.0;
+            self.complete = true;
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete || reduced_motion()
+    }
+
+    fn get_value(&self) -> f32 {
+        if reduced_motion() {
+            return 100.0;
+        }
+
+        self.position * 100.0
+    }
+
+    #[cfg(any(feature = "stack", feature = "drawer"))]
+    fn reverse(&mut self) {
+        self.position = 1.0 - self.position;
+        self.velocity = -self.velocity;
+        self.complete = false;
+        self.last = Instant::now();
+    }
+}
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
 #[allow(missing_debug_implementations)]
 #[derive(Debug, Clone)]
-pub struct Frame {
+pub(crate) struct Frame {
     start: Instant,
     percent: f32,
     duration: Duration,
-    f: Option<fn(f32) -> f32>,
+    f: Option<Easing>,
 }
 
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
 #[allow(dead_code)]
 impl Frame {
     pub fn new() -> Self {
@@ -22,32 +272,115 @@ impl Frame {
         }
     }
 
+    /// Negative or NaN durations finish instantly; durations too large to represent saturate.
     pub fn duration(mut self, duration: impl Into<f32>) -> Self {
-        self.duration = Duration::from_secs_f32(duration.into());
+        self.duration =
+            Duration::try_from_secs_f32(duration.into().max(0.0)).unwrap_or(Duration::MAX);
 
         self
     }
 
-    pub fn map(mut self, f: fn(f32) -> f32) -> Self {
+    pub fn map(mut self, f: Easing) -> Self {
         self.f = Some(f);
         self
     }
 
-    pub fn update(&mut self) {
+    fn is_instant(&self) -> bool {
+        reduced_motion() || self.duration.is_zero()
+    }
+
+    fn ease(&self, progress: f32) -> f32 {
+        self.f.map_or(progress, |f| f(progress))
+    }
+}
+
+#[cfg(any(feature = "stack", feature = "tabs", feature = "drawer"))]
+impl Animation for Frame {
+    fn update(&mut self) {
+        if self.is_instant() {
+            self.percent = 100.0;
+            return;
+        }
+
         let progress = (Instant::now() - self.start).as_secs_f32();
 
         self.percent = (progress / self.duration.as_secs_f32()).min(1.0) * 100.0;
     }
 
-    pub fn is_complete(&self) -> bool {
-        self.percent == 100.0
+    fn is_complete(&self) -> bool {
+        self.is_instant() || self.percent == 100.0
     }
 
-    pub fn get_value(&self) -> f32 {
-        if let Some(f) = self.f {
-            return f(self.percent);
+    fn get_value(&self) -> f32 {
+        if self.is_instant() {
+            return 100.0;
         }
 
-        self.percent
+        self.ease(self.percent / 100.0) * 100.0
+    }
+
+    #[cfg(any(feature = "stack", feature = "drawer"))]
+    fn reverse(&mut self) {
+        let target = 1.0 - self.ease(self.percent / 100.0);
+        let (mut low, mut high) = (0.0, 1.0);
+
+        for _ in 0..24 {
+            let middle = (low + high) / 2.0;
+
+            if self.ease(middle) < target {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        let now = Instant::now();
+
+        self.percent = low * 100.0;
+        self.start = Duration::try_from_secs_f32(self.duration.as_secs_f32() * low)
+            .ok()
+            .and_then(|elapsed| now.checked_sub(elapsed))
+            .unwrap_or(now);
+    }
+}
+
+#[cfg(all(test, any(feature = "stack", feature = "tabs", feature = "drawer")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_clamps_out_of_range_input() {
+        assert_eq!(Frame::new().duration(-1.0).duration, Duration::ZERO);
+        assert_eq!(Frame::new().duration(f32::NAN).duration, Duration::ZERO);
+        assert_eq!(Frame::new().duration(f32::INFINITY).duration, Duration::MAX);
+        assert_eq!(Frame::new().duration(f32::MAX).duration, Duration::MAX);
+    }
+
+    #[test]
+    fn spring_completes_immediately_when_invalid() {
+        for spring in [
+            Spring::new(0.0, 26.0, 1.0),
+            Spring::new(170.0, -1.0, 1.0),
+            Spring::new(170.0, 26.0, 0.0),
+            Spring::new(f32::NAN, 26.0, 1.0),
+        ] {
+            let mut frame = SpringFrame::new(spring);
+
+            frame.update();
+
+            assert!(frame.is_complete());
+            assert_eq!(frame.get_value(), 100.0);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "stack", feature = "drawer"))]
+    fn reverse_keeps_saturated_duration_running() {
+        let mut frame = Frame::new().duration(f32::INFINITY);
+
+        frame.update();
+        frame.reverse();
+
+        assert!(!frame.is_complete());
     }
 }
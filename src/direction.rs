@@ -0,0 +1,36 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static RIGHT_TO_LEFT: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    pub fn is_rtl(self) -> bool {
+        matches!(self, Self::RightToLeft)
+    }
+
+    #[cfg(any(feature = "stack", feature = "tabs"))]
+    pub(crate) fn mirror(self, value: f32) -> f32 {
+        match self {
+            Self::LeftToRight => value,
+            Self::RightToLeft => -value,
+        }
+    }
+}
+
+pub fn set_layout_direction(direction: LayoutDirection) {
+    RIGHT_TO_LEFT.store(direction.is_rtl(), Ordering::Relaxed);
+}
+
+pub fn layout_direction() -> LayoutDirection {
+    if RIGHT_TO_LEFT.load(Ordering::Relaxed) {
+        LayoutDirection::RightToLeft
+    } else {
+        LayoutDirection::LeftToRight
+    }
+}
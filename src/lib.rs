@@ -1,11 +1,14 @@
 mod widgets;
 
+pub mod direction;
 #[cfg(feature = "drawer")]
 pub use widgets::drawer_navigator;
+#[cfg(any(feature = "stack", feature = "drawer"))]
+pub use widgets::header;
 pub use widgets::operations;
 #[cfg(feature = "stack")]
 pub use widgets::stack_navigator;
 #[cfg(feature = "tabs")]
 pub use widgets::tabs_navigator;
 
-pub(crate) mod animation;
+pub mod animation;
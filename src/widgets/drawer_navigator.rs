@@ -4,16 +4,20 @@ use std::mem::Discriminant;
 use std::ops::Div;
 
 use iced_core::{
-    Clipboard, Color, Element, Event, Layout, Length, Padding, Rectangle, Shell, Size, Theme,
-    Vector, Widget, layout, mouse, overlay,
+    Clipboard, Color, Element, Event, Layout, Length, Padding, Point, Rectangle, Shell, Size,
+    Theme, Vector, Widget, layout, mouse, overlay,
     renderer::{self, Quad},
+    text,
+    time::Instant,
     touch,
     widget::{Id, Operation, Tree, tree},
     window,
 };
 use indexmap::IndexMap;
 
-use crate::animation::Frame;
+use crate::animation::{Animation, Animator, Easing, Frame, Spring, SpringFrame, easing};
+use crate::direction::{self, LayoutDirection};
+use crate::widgets::header::{self, Control, DefaultHeader, HeaderView};
 use crate::widgets::{NavigatorElement, NavigatorElementSource, NavigatorState};
 
 type HeaderBuilder<'a, Key, Message, Theme, Renderer> =
@@ -24,16 +28,49 @@ type DrawerBuilder<'a, Key, Message, Theme, Renderer> =
 
 type OnNavigationEnd<'a, Key, Message> = dyn Fn(Option<Key>, Key) -> Message + 'a;
 
+const EDGE_WIDTH: f32 = 20.0;
+const DRAG_THRESHOLD: f32 = 10.0;
+const FLING_PROJECTION: f32 = 0.2;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Drag {
+    origin: Point,
+    start: f32,
+    openness: f32,
+    velocity: f32,
+    time: Instant,
+    dragging: bool,
+}
+
+impl Drag {
+    fn new(origin: Point, start: f32) -> Self {
+        Self {
+            origin,
+            start,
+            openness: start,
+            velocity: 0.0,
+            time: Instant::now(),
+            dragging: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct State<Key: Eq + Hash> {
     pub(crate) history: Vec<Key>,
     pub(crate) previous_page: Option<Key>,
     pub(crate) pending_update: bool,
     pub(crate) transition: Option<Transition>,
-    pub(crate) frame: Option<Frame>,
+    pub(crate) frame: Option<Animator>,
     pub(crate) expanded: bool,
     pub(crate) overlay_pressed: bool,
     pub(crate) navigated: bool,
+    pub(crate) transition_duration: f32,
+    pub(crate) easing: Easing,
+    pub(crate) spring: Option<Spring>,
+    pub(crate) drag: Option<Drag>,
+    pub(crate) start: f32,
+    pub(crate) mode: DrawerMode,
 }
 
 impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
@@ -91,14 +128,30 @@ impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
 }
 
 impl<Key: 'static + Eq + Hash + Clone> State<Key> {
+    fn new_frame(&self, velocity: f32) -> Animator {
+        match self.spring {
+            Some(spring) => SpringFrame::new(spring).velocity(velocity).into(),
+            None => Frame::new()
+                .duration(self.transition_duration)
+                .map(self.easing)
+                .into(),
+        }
+    }
+
     pub fn open_drawer(&mut self) {
         if self.expanded {
             return;
         }
 
+        let openness = self.openness().unwrap_or(0.0);
+
         self.expanded = true;
-        self.frame = Some(Frame::new().duration(0.2));
         self.transition = Some(Transition::Expandion);
+
+        match self.frame.as_mut() {
+            Some(frame) if self.start == 0.0 => frame.reverse(),
+            _ => self.settle(openness, 0.0),
+        }
     }
 
     pub fn close_drawer(&mut self) {
@@ -106,9 +159,54 @@ impl<Key: 'static + Eq + Hash + Clone> State<Key> {
             return;
         }
 
+        let openness = self.openness().unwrap_or(1.0);
+
         self.expanded = false;
-        self.frame = Some(Frame::new().duration(0.2));
         self.transition = Some(Transition::Collapse);
+
+        match self.frame.as_mut() {
+            Some(frame) if self.start == 0.0 => frame.reverse(),
+            _ => self.settle(openness, 0.0),
+        }
+    }
+
+    fn settle(&mut self, openness: f32, velocity: f32) {
+        let (start, velocity) = match self.transition {
+            Some(Transition::Collapse) => (1.0 - openness, -velocity),
+            _ => (openness, velocity),
+        };
+
+        self.start = start.clamp(0.0, 1.0);
+        self.frame = Some(self.new_frame(velocity / (1.0 - self.start).max(0.01)));
+    }
+
+    fn set_mode(&mut self, mode: DrawerMode) {
+        if self.mode == mode {
+            return;
+        }
+
+        self.mode = mode;
+        self.expanded = false;
+        self.transition = None;
+        self.frame = None;
+        self.start = 0.0;
+        self.drag = None;
+        self.overlay_pressed = false;
+    }
+
+    fn is_dragging(&self) -> bool {
+        self.drag.as_ref().is_some_and(|drag| drag.dragging)
+    }
+
+    fn openness(&self) -> Option<f32> {
+        if let Some(drag) = self.drag.as_ref().filter(|drag| drag.dragging) {
+            return Some(drag.openness);
+        }
+
+        let transition = self.transition.as_ref()?;
+        let progress = self.frame.as_ref()?.get_value().div(100.0).clamp(0.0, 1.0);
+
+        Some(transition.openness(self.start + (1.0 - self.start) * progress))
     }
 }
 
@@ -118,10 +216,99 @@ pub struct PageParams<Key> {
     pub can_go_back: bool,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DrawerMode {
     Fixed,
     Sliding,
+    Responsive { breakpoint: f32 },
+}
+
+impl DrawerMode {
+    fn resolve(self, width: f32) -> Self {
+        match self {
+            Self::Responsive { breakpoint } if width >= breakpoint => Self::Fixed,
+            Self::Responsive { .. } => Self::Sliding,
+            mode => mode,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DrawerPosition {
+    #[default]
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl DrawerPosition {
+    fn resolve(self, direction: LayoutDirection) -> Self {
+        match (self, direction) {
+            (Self::Left, LayoutDirection::RightToLeft) => Self::Right,
+            (Self::Right, LayoutDirection::RightToLeft) => Self::Left,
+            (position, _) => position,
+        }
+    }
+
+    fn axis(self) -> layout::flex::Axis {
+        match self {
+            Self::Left | Self::Right => layout::flex::Axis::Horizontal,
+            Self::Top | Self::Bottom => layout::flex::Axis::Vertical,
+        }
+    }
+
+    fn trails(self) -> bool {
+        matches!(self, Self::Right | Self::Bottom)
+    }
+
+    fn extent(self, size: Size) -> f32 {
+        match self {
+            Self::Left | Self::Right => size.width,
+            Self::Top | Self::Bottom => size.height,
+        }
+    }
+
+    fn split(self, delta: Vector) -> (f32, f32) {
+        match self {
+            Self::Left => (delta.x, delta.y),
+            Self::Right => (-delta.x, delta.y),
+            Self::Top => (delta.y, delta.x),
+            Self::Bottom => (-delta.y, delta.x),
+        }
+    }
+
+    fn at_edge(self, bounds: Rectangle, point: Point) -> bool {
+        let distance = match self {
+            Self::Left => point.x - bounds.x,
+            Self::Right => bounds.x + bounds.width - point.x,
+            Self::Top => point.y - bounds.y,
+            Self::Bottom => bounds.y + bounds.height - point.y,
+        };
+
+        distance <= EDGE_WIDTH
+    }
+
+    fn offset(self, size: Size, hidden: f32) -> Vector {
+        match self {
+            Self::Left => Vector::new(-size.width * hidden, 0.0),
+            Self::Right => Vector::new(size.width * hidden, 0.0),
+            Self::Top => Vector::new(0.0, -size.height * hidden),
+            Self::Bottom => Vector::new(0.0, size.height * hidden),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DrawerWidth {
+    Length(Length),
+    Fraction(f32),
+}
+
+impl From<Length> for DrawerWidth {
+    fn from(length: Length) -> Self {
+        Self::Length(length)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -140,6 +327,7 @@ where
     home_page: Key,
     pages: Vec<Key>,
     header_builder: Option<Box<HeaderBuilder<'a, Key, Message, Theme, Renderer>>>,
+    default_header: Option<Box<HeaderView<'a, Key, Message, Renderer>>>,
     drawer_builder: Option<Box<DrawerBuilder<'a, Key, Message, Theme, Renderer>>>,
     header_cache: NavigatorElement<'a, PageParams<Key>, Message, Theme, Renderer>,
     drawer_cache: NavigatorElement<'a, PageParams<Key>, Message, Theme, Renderer>,
@@ -149,7 +337,15 @@ where
     >,
     on_navigation_end: Option<Box<OnNavigationEnd<'a, Key, Message>>>,
     mode: DrawerMode,
+    position: DrawerPosition,
+    drawer_width: Option<DrawerWidth>,
+    min_drawer_width: f32,
+    max_drawer_width: f32,
     overlay: bool,
+    transition_duration: f32,
+    easing: Easing,
+    spring: Option<Spring>,
+    direction: Option<LayoutDirection>,
 }
 
 impl<'a, Key, Message, Renderer> DrawerNavigator<'a, Key, Message, Renderer>
@@ -162,6 +358,7 @@ where
             width: Length::Fill,
             height: Length::Fill,
             header_builder: None,
+            default_header: None,
             drawer_builder: None,
             drawer_cache: NavigatorElement::empty(),
             header_cache: NavigatorElement::empty(),
@@ -169,7 +366,15 @@ where
             children: IndexMap::new(),
             on_navigation_end: None,
             mode: DrawerMode::Sliding,
+            position: DrawerPosition::Left,
+            drawer_width: None,
+            min_drawer_width: 0.0,
+            max_drawer_width: f32::INFINITY,
             overlay: false,
+            transition_duration: 0.2,
+            easing: easing::linear,
+            spring: None,
+            direction: None,
             home_page,
         }
     }
@@ -235,6 +440,17 @@ where
         self
     }
 
+    pub fn default_header(mut self, header: DefaultHeader<'a, Key, Message, Renderer>) -> Self
+    where
+        Key: 'a,
+        Message: 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        self.default_header = Some(header.into_view());
+
+        self
+    }
+
     pub fn on_navigation_end(
         mut self,
         on_navigation_end: impl Fn(Option<Key>, Key) -> Message + 'a,
@@ -250,11 +466,187 @@ where
         self
     }
 
+    pub fn position(mut self, position: DrawerPosition) -> Self {
+        self.position = position;
+
+        self
+    }
+
+    pub fn drawer_width(mut self, width: impl Into<DrawerWidth>) -> Self {
+        self.drawer_width = Some(width.into());
+
+        self
+    }
+
+    pub fn min_drawer_width(mut self, width: f32) -> Self {
+        self.min_drawer_width = width;
+
+        self
+    }
+
+    pub fn max_drawer_width(mut self, width: f32) -> Self {
+        self.max_drawer_width = width;
+
+        self
+    }
+
     pub fn overlay(mut self, overlay: bool) -> Self {
         self.overlay = overlay;
 
         self
     }
+
+    pub fn transition_duration(mut self, duration: f32) -> Self {
+        self.transition_duration = duration;
+
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+
+        self
+    }
+
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.spring = Some(spring);
+
+        self
+    }
+
+    pub fn direction(mut self, direction: LayoutDirection) -> Self {
+        self.direction = Some(direction);
+
+        self
+    }
+
+    fn get_direction(&self) -> LayoutDirection {
+        self.direction.unwrap_or_else(direction::layout_direction)
+    }
+
+    fn get_position(&self) -> DrawerPosition {
+        self.position.resolve(self.get_direction())
+    }
+
+    fn update_drag(
+        &self,
+        state: &mut State<Key>,
+        event: &Event,
+        bounds: Rectangle,
+        drawer: Rectangle,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool
+    where
+        Key: 'static,
+    {
+        let position = self.get_position();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.drag = cursor
+                    .position_over(bounds)
+                    .filter(|point| state.expanded || position.at_edge(bounds, *point))
+                    .map(|point| Drag::new(point, if state.expanded { 1.0 } else { 0.0 }));
+
+                false
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position: point })
+            | Event::Touch(touch::Event::FingerMoved {
+                position: point, ..
+            }) => {
+                let Some(drag) = state.drag.as_mut() else {
+                    return false;
+                };
+
+                let (along, across) = position.split(*point - drag.origin);
+
+                if !drag.dragging {
+                    if along.abs() < DRAG_THRESHOLD || along.abs() < across.abs() {
+                        return false;
+                    }
+
+                    drag.dragging = true;
+                    state.overlay_pressed = false;
+                }
+
+                let extent = position.extent(drawer.size()).max(1.0);
+                let openness = (drag.start + along / extent).clamp(0.0, 1.0);
+
+                let now = Instant::now();
+                let elapsed = (now - drag.time).as_secs_f32();
+
+                if elapsed > 0.0 {
+                    drag.velocity = (openness - drag.openness) / elapsed;
+                }
+
+                drag.openness = openness;
+                drag.time = now;
+
+                shell.request_redraw();
+                shell.capture_event();
+                true
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take().filter(|drag| drag.dragging) else {
+                    return false;
+                };
+
+                let projected = drag.openness + drag.velocity * FLING_PROJECTION;
+
+                state.expanded = projected > 0.5;
+                state.transition = Some(if state.expanded {
+                    Transition::Expandion
+                } else {
+                    Transition::Collapse
+                });
+                state.settle(drag.openness, drag.velocity);
+
+                shell.request_redraw();
+                shell.capture_event();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn is_sized(&self) -> bool {
+        self.drawer_width.is_some()
+            || self.min_drawer_width > 0.0
+            || self.max_drawer_width.is_finite()
+    }
+
+    fn drawer_limits(&self, limits: &layout::Limits, position: DrawerPosition) -> layout::Limits {
+        let horizontal = matches!(position.axis(), layout::flex::Axis::Horizontal);
+        let available = if horizontal {
+            limits.max().width
+        } else {
+            limits.max().height
+        };
+
+        let extent = match self.drawer_width {
+            Some(DrawerWidth::Fraction(fraction)) => Some(available * fraction.clamp(0.0, 1.0)),
+            Some(DrawerWidth::Length(Length::Fixed(width))) => Some(width),
+            Some(DrawerWidth::Length(Length::Fill | Length::FillPortion(_))) => Some(available),
+            Some(DrawerWidth::Length(Length::Shrink)) | None => None,
+        };
+
+        let min = self.min_drawer_width.min(available);
+        let max = self.max_drawer_width.min(available).max(min);
+        let (min, max) = match extent {
+            Some(extent) => (extent.clamp(min, max), extent.clamp(min, max)),
+            None => (min, max),
+        };
+
+        if horizontal {
+            limits.min_width(min).max_width(max)
+        } else {
+            limits.min_height(min).max_height(max)
+        }
+    }
 }
 
 impl<'a, Key, Message, Renderer> Widget<Message, Theme, Renderer>
@@ -285,6 +677,12 @@ where
             expanded: false,
             overlay_pressed: false,
             navigated: false,
+            transition_duration: self.transition_duration,
+            easing: self.easing,
+            spring: self.spring,
+            drag: None,
+            start: 0.0,
+            mode: self.mode.resolve(0.0),
         })
     }
 
@@ -316,7 +714,7 @@ where
 
         let (header_layout, drawer_layout, page_layout) = get_layout(
             layout,
-            self.mode,
+            state.mode,
             !self.header_cache.is_empty(),
             !self.drawer_cache.is_empty(),
         );
@@ -358,7 +756,7 @@ where
 
         operation.custom(self.id.as_ref(), layout.bounds(), state);
 
-        if let DrawerMode::Fixed = self.mode
+        if let DrawerMode::Fixed = state.mode
             && state.transition.is_some()
         {
             state.transition = None;
@@ -377,6 +775,12 @@ where
         while tree.children.len() < self.children.len() + 2 {
             tree.children.insert(tree.children.len() - 3, Tree::empty());
         }
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        state.transition_duration = self.transition_duration;
+        state.easing = self.easing;
+        state.spring = self.spring;
     }
 
     fn layout(
@@ -386,8 +790,13 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let limits = limits.width(self.width).height(self.height);
+        let direction = self.get_direction();
+        let position = self.get_position();
+        let drawer_limits = self.drawer_limits(&limits, position);
+        let sized = self.is_sized();
+        let has_header = self.header_builder.is_some() || self.default_header.is_some();
 
-        if self.header_builder.is_none() {
+        if !has_header {
             self.header_cache.clear_cache();
         }
 
@@ -396,6 +805,9 @@ where
         }
 
         let state = tree.state.downcast_mut::<State<Key>>();
+        let mode = self.mode.resolve(limits.max().width);
+
+        state.set_mode(mode);
 
         let key = state.history.last().unwrap();
         let disc = std::mem::discriminant(key);
@@ -414,22 +826,30 @@ where
         self.children
             .get_mut(&disc)
             .map(|page| {
-                let mut page_header = self.header_builder.as_ref().map(|builder| {
-                    let element = builder(params.clone());
-
-                    tree.children[header_index].diff(&element);
-
-                    element
+                let control =
+                    if matches!(mode, DrawerMode::Sliding) && self.drawer_builder.is_some() {
+                        Some(Control::Menu)
+                    } else {
+                        params.can_go_back.then_some(Control::Back)
+                    };
+
+                let mut page_header = match self.header_builder.as_ref() {
+                    Some(builder) => Some(builder(params.clone())),
+                    None => self
+                        .default_header
+                        .as_ref()
+                        .map(|view| view(&params.current_page, control, direction)),
+                }
+                .inspect(|element| {
+                    tree.children[header_index].diff(element);
                 });
 
                 let header_node = page_header.as_mut().map(|header| {
-                    let node = header.as_widget_mut().layout(
+                    header.as_widget_mut().layout(
                         &mut tree.children[header_index],
                         renderer,
                         &limits,
-                    );
-
-                    node
+                    )
                 });
 
                 let page_drawer = self.drawer_builder.as_ref().map(|builder| {
@@ -457,11 +877,54 @@ where
                 tree.children.swap(child_index, page_index);
                 tree.children.swap(drawer_index, page_index);
 
-                let node = match self.mode {
+                let node = match mode {
                     DrawerMode::Fixed => {
-                        let mut base = if self.drawer_builder.is_some() {
-                            layout::flex::resolve(
-                                layout::flex::Axis::Horizontal,
+                        let mut base = if self.drawer_builder.is_some() && sized {
+                            let items_len = items.len();
+
+                            let drawer_node = items[items_len - 2].as_widget_mut().layout(
+                                &mut tree.children[page_index],
+                                renderer,
+                                &drawer_limits,
+                            );
+
+                            let offset = match position.axis() {
+                                layout::flex::Axis::Horizontal => {
+                                    Vector::new(drawer_node.size().width, 0.0)
+                                }
+                                layout::flex::Axis::Vertical => {
+                                    Vector::new(0.0, drawer_node.size().height)
+                                }
+                            };
+
+                            let page_node = items[items_len - 1]
+                                .as_widget_mut()
+                                .layout(
+                                    &mut tree.children[drawer_index],
+                                    renderer,
+                                    &limits.shrink(Size::new(offset.x, offset.y)),
+                                )
+                                .translate(offset);
+
+                            let size = limits.resolve(
+                                self.width,
+                                self.height,
+                                Size::new(
+                                    page_node.size().width + offset.x,
+                                    page_node.size().height + offset.y,
+                                ),
+                            );
+                            let node =
+                                layout::Node::with_children(size, vec![drawer_node, page_node]);
+
+                            if position.trails() {
+                                trail_drawer(node, position)
+                            } else {
+                                node
+                            }
+                        } else if self.drawer_builder.is_some() {
+                            let node = layout::flex::resolve(
+                                position.axis(),
                                 renderer,
                                 &limits,
                                 self.width,
@@ -469,9 +932,15 @@ where
                                 Padding::ZERO,
                                 0.0,
                                 iced_core::Alignment::Start,
-                                &mut items[if self.header_builder.is_some() { 1 } else { 0 }..],
+                                &mut items[if has_header { 1 } else { 0 }..],
                                 &mut tree.children[page_index..=drawer_index],
-                            )
+                            );
+
+                            if position.trails() {
+                                trail_drawer(node, position)
+                            } else {
+                                node
+                            }
                         } else {
                             items.last_mut().unwrap().as_widget_mut().layout(
                                 &mut tree.children[drawer_index],
@@ -490,14 +959,14 @@ where
                             None => base,
                         }
                     }
-                    DrawerMode::Sliding => {
+                    DrawerMode::Sliding | DrawerMode::Responsive { .. } => {
                         let items_len = items.len();
 
-                        let drawer_node = if self.drawer_builder.is_some() {
+                        let mut drawer_node = if self.drawer_builder.is_some() {
                             Some(items[items_len - 2].as_widget_mut().layout(
                                 &mut tree.children[page_index],
                                 renderer,
-                                &limits,
+                                &drawer_limits,
                             ))
                         } else {
                             None
@@ -509,6 +978,23 @@ where
                             &limits,
                         );
 
+                        if let Some(node) = drawer_node.as_mut() {
+                            let page_size = page_node.size();
+                            let drawer_size = node.size();
+
+                            match position {
+                                DrawerPosition::Right => node.move_to_mut(Point::new(
+                                    page_size.width - drawer_size.width,
+                                    0.0,
+                                )),
+                                DrawerPosition::Bottom => node.move_to_mut(Point::new(
+                                    0.0,
+                                    page_size.height - drawer_size.height,
+                                )),
+                                DrawerPosition::Left | DrawerPosition::Top => {}
+                            }
+                        }
+
                         match header_node {
                             Some(header_node) => {
                                 let base_size = page_node.size();
@@ -534,7 +1020,7 @@ where
                     }
                 };
 
-                if self.header_builder.is_some() {
+                if has_header {
                     self.header_cache.return_element(items.remove(0));
                 }
 
@@ -588,6 +1074,7 @@ where
                 if frame.is_complete() {
                     state.frame = None;
                     state.transition = None;
+                    state.start = 0.0;
                 } else {
                     frame.update()
                 }
@@ -609,15 +1096,29 @@ where
         let drawer_index = children_len - 2;
 
         let has_drawer = self.drawer_builder.is_some()
-            && (state.expanded || matches!(self.mode, DrawerMode::Fixed));
+            && (state.expanded || matches!(state.mode, DrawerMode::Fixed));
 
         let (header_layout, drawer_layout, page_layout) = get_layout(
             layout,
-            self.mode,
+            state.mode,
             !self.header_cache.is_empty(),
             !self.drawer_cache.is_empty(),
         );
 
+        if let DrawerMode::Sliding = state.mode
+            && let Some(drawer) = drawer_layout
+            && self.update_drag(
+                state,
+                event,
+                layout.bounds(),
+                drawer.bounds(),
+                cursor,
+                shell,
+            )
+        {
+            return;
+        }
+
         if let Some(child) = self.drawer_cache.get_element_mut()
             && has_drawer
         {
@@ -635,7 +1136,7 @@ where
 
         if let Some(header) = self.header_cache.get_element_mut()
             && (!state.expanded
-                || !matches!(self.mode, DrawerMode::Sliding)
+                || !matches!(state.mode, DrawerMode::Sliding)
                 || matches!(event, Event::Window(_)))
         {
             header.as_widget_mut().update(
@@ -648,9 +1149,27 @@ where
                 shell,
                 viewport,
             );
-        };
 
-        if let DrawerMode::Sliding = self.mode
+            let handled = match header::take_action(&mut tree.children[header_index]) {
+                Some(Control::Menu) => {
+                    state.open_drawer();
+                    true
+                }
+                Some(Control::Back) if state.history.len() > 1 => {
+                    state.go_back();
+                    true
+                }
+                _ => false,
+            };
+
+            if handled {
+                shell.invalidate_layout();
+                shell.request_redraw();
+                return;
+            }
+        }
+
+        if let DrawerMode::Sliding = state.mode
             && state.expanded
         {
             let page_bounds = page_layout.unwrap().bounds();
@@ -658,25 +1177,26 @@ where
                 .map(|header| page_bounds.union(&header.bounds()))
                 .unwrap_or(page_bounds);
 
+            let over_scrim =
+                cursor.is_over(bounds) && !cursor.is_over(drawer_layout.unwrap().bounds());
+
             match event {
                 Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if cursor.is_over(bounds) && !cursor.is_over(drawer_layout.unwrap().bounds()) {
-                        state.overlay_pressed = true;
-                    }
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if over_scrim =>
+                {
+                    state.overlay_pressed = true;
                 }
                 Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                | Event::Touch(touch::Event::FingerLifted { .. }) => {
-                    if state.overlay_pressed {
-                        state.overlay_pressed = false;
-
-                        if cursor.is_over(bounds)
-                            && !cursor.is_over(drawer_layout.unwrap().bounds())
-                        {
-                            state.close_drawer();
-                            state.pending_update = false;
-                            shell.request_redraw();
-                        }
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                    if state.overlay_pressed =>
+                {
+                    state.overlay_pressed = false;
+
+                    if over_scrim {
+                        state.close_drawer();
+                        state.pending_update = false;
+                        shell.request_redraw();
                     }
                 }
                 _ => {}
@@ -717,6 +1237,11 @@ where
         renderer: &Renderer,
     ) -> mouse::Interaction {
         let state = tree.state.downcast_ref::<State<Key>>();
+
+        if state.is_dragging() {
+            return mouse::Interaction::Grabbing;
+        }
+
         let children_len = tree.children.len();
         let key = state.history.last().unwrap();
         let disc = std::mem::discriminant(key);
@@ -726,11 +1251,11 @@ where
         let drawer_index = children_len - 2;
 
         let has_drawer = self.drawer_builder.is_some()
-            && (state.expanded || matches!(self.mode, DrawerMode::Fixed));
+            && (state.expanded || matches!(state.mode, DrawerMode::Fixed));
 
         let (header_layout, drawer_layout, page_layout) = get_layout(
             layout,
-            self.mode,
+            state.mode,
             !self.header_cache.is_empty(),
             !self.drawer_cache.is_empty(),
         );
@@ -756,7 +1281,7 @@ where
             None
         };
 
-        if let DrawerMode::Sliding = self.mode
+        if let DrawerMode::Sliding = state.mode
             && self.overlay
             && state.expanded
         {
@@ -822,7 +1347,7 @@ where
 
             let (header_layout, drawer_layout, page_layout) = get_layout(
                 layout,
-                self.mode,
+                state.mode,
                 !self.header_cache.is_empty(),
                 !self.drawer_cache.is_empty(),
             );
@@ -854,7 +1379,7 @@ where
             }
 
             if let Some(child) = self.drawer_cache.get_element() {
-                match self.mode {
+                match state.mode {
                     DrawerMode::Fixed => {
                         child.as_widget().draw(
                             &tree.children[drawer_index],
@@ -866,7 +1391,9 @@ where
                             &clipped_viewport,
                         );
                     }
-                    DrawerMode::Sliding if state.expanded || state.transition.is_some() => {
+                    DrawerMode::Sliding
+                        if state.expanded || state.transition.is_some() || state.is_dragging() =>
+                    {
                         let layout = drawer_layout.unwrap();
 
                         let overlay = if self.overlay {
@@ -884,10 +1411,10 @@ where
                             None
                         };
 
-                        match state.transition.as_ref().map(|transition| {
-                            transition.to_translation(state.frame.as_ref(), &layout.bounds())
+                        match state.openness().map(|openness| {
+                            to_translation(openness, &layout.bounds(), self.get_position())
                         }) {
-                            Some(Some((translation, opacity))) => {
+                            Some((translation, opacity)) => {
                                 if self.overlay {
                                     renderer.with_layer(clipped_viewport, |renderer| {
                                         renderer.fill_quad(
@@ -897,22 +1424,19 @@ where
                                     });
                                 }
 
-                                renderer.with_translation(
-                                    Vector::new(translation, 0.0),
-                                    |renderer| {
-                                        renderer.with_layer(clipped_viewport, |renderer| {
-                                            child.as_widget().draw(
-                                                &tree.children[drawer_index],
-                                                renderer,
-                                                theme,
-                                                style,
-                                                layout,
-                                                cursor,
-                                                &clipped_viewport,
-                                            );
-                                        });
-                                    },
-                                );
+                                renderer.with_translation(translation, |renderer| {
+                                    renderer.with_layer(clipped_viewport, |renderer| {
+                                        child.as_widget().draw(
+                                            &tree.children[drawer_index],
+                                            renderer,
+                                            theme,
+                                            style,
+                                            layout,
+                                            cursor,
+                                            &clipped_viewport,
+                                        );
+                                    });
+                                });
                             }
                             _ => {
                                 if self.overlay {
@@ -955,7 +1479,7 @@ where
         if let Some(clipped_viewport) = layout.bounds().intersection(viewport) {
             let state = tree.state.downcast_ref::<State<Key>>();
 
-            if state.pending_update || state.transition.is_some() {
+            if state.pending_update || state.transition.is_some() || state.is_dragging() {
                 return None;
             }
 
@@ -966,11 +1490,13 @@ where
 
             let (header_layout, drawer_layout, page_layout) = get_layout(
                 layout,
-                self.mode,
+                state.mode,
                 !self.header_cache.is_empty(),
                 !self.drawer_cache.is_empty(),
             );
 
+            let position = self.get_position();
+
             let (header_state, tree_slice) = tree.children.split_last_mut().unwrap();
             let (drawer_state, tree_slice) = tree_slice.split_last_mut().unwrap();
             let page_state = &mut tree_slice[page_index];
@@ -986,7 +1512,7 @@ where
             });
 
             let drawer_overlay = self.drawer_cache.get_element_mut().map(|element| {
-                if let DrawerMode::Sliding = self.mode
+                if let DrawerMode::Sliding = state.mode
                     && !state.expanded
                     && state.transition.is_none()
                 {
@@ -995,17 +1521,12 @@ where
 
                 let drawer_layout = drawer_layout.unwrap();
 
-                let drawer_translation = state.transition.as_ref().map(|transition| {
-                    transition
-                        .to_translation(state.frame.as_ref(), &drawer_layout.bounds())
-                        .map(|(value, _)| value)
-                        .unwrap_or(0.0)
-                });
+                let drawer_translation = state
+                    .openness()
+                    .map(|openness| to_translation(openness, &drawer_layout.bounds(), position).0)
+                    .unwrap_or(Vector::ZERO);
 
-                let translation = Vector {
-                    x: translation.x + drawer_translation.unwrap_or(0.0),
-                    y: translation.y,
-                };
+                let translation = translation + drawer_translation;
 
                 element.as_widget_mut().overlay(
                     drawer_state,
@@ -1044,23 +1565,32 @@ where
 }
 
 impl Transition {
-    fn to_translation(&self, frame: Option<&Frame>, area: &Rectangle) -> Option<(f32, f32)> {
-        let width = area.width;
-        let frame = frame?;
-
+    fn openness(&self, progress: f32) -> f32 {
         match self {
-            Self::Expandion => Some((
-                (frame.get_value().div(100.0) - 1.0) * width,
-                frame.get_value().div(100.0) * 0.6,
-            )),
-            Self::Collapse => Some((
-                ((frame.get_value().div(100.0) - 1.0) * width).abs() - width,
-                ((frame.get_value().div(100.0) * 0.6) - 0.6).abs(),
-            )),
+            Self::Expandion => progress,
+            Self::Collapse => 1.0 - progress,
         }
     }
 }
 
+fn to_translation(openness: f32, area: &Rectangle, position: DrawerPosition) -> (Vector, f32) {
+    (position.offset(area.size(), 1.0 - openness), openness * 0.6)
+}
+
+fn trail_drawer(node: layout::Node, position: DrawerPosition) -> layout::Node {
+    let mut children = node.children().to_vec();
+    let page_size = children[1].size();
+
+    children[1].move_to_mut(Point::ORIGIN);
+
+    match position {
+        DrawerPosition::Bottom => children[0].move_to_mut(Point::new(0.0, page_size.height)),
+        _ => children[0].move_to_mut(Point::new(page_size.width, 0.0)),
+    };
+
+    layout::Node::with_children(node.size(), children)
+}
+
 fn get_layout<'a>(
     layout: Layout<'a>,
     mode: DrawerMode,
@@ -1080,7 +1610,7 @@ fn get_layout<'a>(
             (None, Some(layout.child(0)), Some(layout.child(1)))
         }
         DrawerMode::Fixed => (None, None, Some(layout)),
-        DrawerMode::Sliding => {
+        DrawerMode::Sliding | DrawerMode::Responsive { .. } => {
             if has_header && has_drawer {
                 (
                     Some(layout.child(0)),
@@ -1119,3 +1649,82 @@ where
 {
     DrawerNavigator::new(home_page)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extent(drawer: DrawerNavigator<'_, (), (), ()>, position: DrawerPosition) -> Size {
+        let limits = layout::Limits::new(Size::ZERO, Size::new(1000.0, 800.0));
+        let limits = drawer.drawer_limits(&limits, position);
+
+        Size::new(limits.max().width, limits.max().height)
+    }
+
+    #[test]
+    fn drawer_width_clamps_fraction_to_max() {
+        let drawer = DrawerNavigator::new(())
+            .drawer_width(DrawerWidth::Fraction(0.5))
+            .max_drawer_width(300.0);
+
+        assert_eq!(extent(drawer, DrawerPosition::Left).width, 300.0);
+    }
+
+    #[test]
+    fn drawer_width_clamps_to_available_space() {
+        let fraction = DrawerNavigator::new(()).drawer_width(DrawerWidth::Fraction(1.5));
+        let fixed = DrawerNavigator::new(()).drawer_width(Length::Fixed(1200.0));
+        let min = DrawerNavigator::new(()).min_drawer_width(1200.0);
+
+        assert_eq!(extent(fraction, DrawerPosition::Left).width, 1000.0);
+        assert_eq!(extent(fixed, DrawerPosition::Right).width, 1000.0);
+        assert_eq!(extent(min, DrawerPosition::Left).width, 1000.0);
+    }
+
+    #[test]
+    fn drawer_width_keeps_min_above_max() {
+        let drawer = DrawerNavigator::new(())
+            .drawer_width(Length::Fixed(100.0))
+            .min_drawer_width(250.0)
+            .max_drawer_width(200.0);
+
+        assert_eq!(extent(drawer, DrawerPosition::Left).width, 250.0);
+    }
+
+    #[test]
+    fn drawer_width_follows_the_vertical_axis() {
+        let drawer = DrawerNavigator::new(()).drawer_width(DrawerWidth::Fraction(0.25));
+        let size = extent(drawer, DrawerPosition::Top);
+
+        assert_eq!(size.width, 1000.0);
+        assert_eq!(size.height, 200.0);
+    }
+
+    #[test]
+    fn to_translation_hides_a_closed_drawer() {
+        let area = Rectangle::new(Point::ORIGIN, Size::new(300.0, 800.0));
+
+        assert_eq!(
+            to_translation(0.0, &area, DrawerPosition::Left),
+            (Vector::new(-300.0, 0.0), 0.0)
+        );
+        assert_eq!(
+            to_translation(0.0, &area, DrawerPosition::Bottom),
+            (Vector::new(0.0, 800.0), 0.0)
+        );
+    }
+
+    #[test]
+    fn to_translation_follows_openness() {
+        let area = Rectangle::new(Point::ORIGIN, Size::new(300.0, 800.0));
+
+        assert_eq!(
+            to_translation(0.5, &area, DrawerPosition::Right),
+            (Vector::new(150.0, 0.0), 0.3)
+        );
+        assert_eq!(
+            to_translation(1.0, &area, DrawerPosition::Top),
+            (Vector::new(0.0, 0.0), 0.6)
+        );
+    }
+}
@@ -0,0 +1,533 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem::Discriminant;
+
+use iced_core::{
+    Border, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Theme,
+    Vector, Widget, layout, mouse, overlay,
+    renderer::{self, Quad},
+    text,
+    widget::{Operation, Tree, tree},
+};
+use iced_widget::{Text, button};
+
+use crate::direction::LayoutDirection;
+
+type ActionsBuilder<'a, Message, Theme, Renderer> =
+    dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'a;
+
+pub(crate) type HeaderView<'a, Key, Message, Renderer> =
+    dyn Fn(&Key, Option<Control>, LayoutDirection) -> Element<'a, Message, Theme, Renderer> + 'a;
+
+const DEFAULT_HEIGHT: f32 = 56.0;
+const PADDING: f32 = 8.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Control {
+    Back,
+    #[cfg_attr(not(feature = "drawer"), allow(dead_code))]
+    Menu,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    action: Option<Control>,
+}
+
+pub struct DefaultHeader<'a, Key, Message, Renderer> {
+    titles: HashMap<Discriminant<Key>, String>,
+    actions: HashMap<Discriminant<Key>, Box<ActionsBuilder<'a, Message, Theme, Renderer>>>,
+    height: f32,
+}
+
+impl<'a, Key, Message, Renderer> DefaultHeader<'a, Key, Message, Renderer>
+where
+    Key: Eq + Hash,
+{
+    pub fn new() -> Self {
+        Self {
+            titles: HashMap::new(),
+            actions: HashMap::new(),
+            height: DEFAULT_HEIGHT,
+        }
+    }
+
+    pub fn title(mut self, key: Key, title: impl Into<String>) -> Self {
+        self.titles
+            .insert(std::mem::discriminant(&key), title.into());
+
+        self
+    }
+
+    pub fn actions(
+        mut self,
+        key: Key,
+        fun: impl Fn() -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        self.actions
+            .insert(std::mem::discriminant(&key), Box::new(fun));
+
+        self
+    }
+
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+
+        self
+    }
+
+    pub(crate) fn into_view(self) -> Box<HeaderView<'a, Key, Message, Renderer>>
+    where
+        Key: 'a,
+        Message: 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        Box::new(move |key, control, direction| self.view(key, control, direction))
+    }
+
+    fn view(
+        &self,
+        key: &Key,
+        control: Option<Control>,
+        direction: LayoutDirection,
+    ) -> Element<'a, Message, Theme, Renderer>
+    where
+        Message: 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        let disc = std::mem::discriminant(key);
+        let title = self.titles.get(&disc).cloned().unwrap_or_default();
+
+        HeaderBar {
+            height: self.height,
+            direction,
+            control,
+            button: control.map(|control| control_button(control, direction, 1.0)),
+            title: Text::new(title).size(18).into(),
+            actions: self.actions.get(&disc).map(|builder| builder()),
+        }
+        .into()
+    }
+}
+
+impl<'a, Key, Message, Renderer> Default for DefaultHeader<'a, Key, Message, Renderer>
+where
+    Key: Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn take_action(tree: &mut Tree) -> Option<Control> {
+    if tree.tag != tree::Tag::of::<State>() {
+        return None;
+    }
+
+    tree.state.downcast_mut::<State>().action.take()
+}
+
+fn control_button<'a, Renderer>(
+    control: Control,
+    direction: LayoutDirection,
+    opacity: f32,
+) -> Element<'a, Control, Theme, Renderer>
+where
+    Renderer: text::Renderer + 'a,
+{
+    let icon = match (control, direction) {
+        (Control::Back, LayoutDirection::LeftToRight) => "←",
+        (Control::Back, LayoutDirection::RightToLeft) => "→",
+        (Control::Menu, _) => "☰",
+    };
+
+    button(Text::new(icon).size(20))
+        .on_press(control)
+        .padding(PADDING)
+        .style(move |theme: &Theme, status| {
+            let palette = theme.extended_palette();
+
+            let background = match status {
+                button::Status::Hovered => {
+                    Some(palette.primary.strong.color.scale_alpha(opacity).into())
+                }
+                button::Status::Pressed => {
+                    Some(palette.primary.weak.color.scale_alpha(opacity).into())
+                }
+                button::Status::Active | button::Status::Disabled => None,
+            };
+
+            button::Style {
+                background,
+                text_color: palette.primary.base.text.scale_alpha(opacity),
+                border: Border::default().rounded(4),
+                ..Default::default()
+            }
+        })
+        .into()
+}
+
+struct HeaderBar<'a, Message, Renderer> {
+    height: f32,
+    direction: LayoutDirection,
+    control: Option<Control>,
+    button: Option<Element<'a, Control, Theme, Renderer>>,
+    title: Element<'a, Message, Theme, Renderer>,
+    actions: Option<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Theme, Renderer> for HeaderBar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Fixed(self.height),
+        }
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            self.button
+                .as_ref()
+                .map(Tree::new)
+                .unwrap_or_else(Tree::empty),
+            Tree::new(&self.title),
+            self.actions
+                .as_ref()
+                .map(Tree::new)
+                .unwrap_or_else(Tree::empty),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if tree.children.len() != 3 {
+            tree.children = self.children();
+            return;
+        }
+
+        match self.button.as_ref() {
+            Some(control) => tree.children[0].diff(control),
+            None => tree.children[0] = Tree::empty(),
+        }
+
+        tree.children[1].diff(&self.title);
+
+        match self.actions.as_ref() {
+            Some(actions) => tree.children[2].diff(actions),
+            None => tree.children[2] = Tree::empty(),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.width(Length::Fill).height(self.height).resolve(
+            Length::Fill,
+            self.height,
+            Size::ZERO,
+        );
+
+        let limits = layout::Limits::new(Size::ZERO, size);
+
+        let control = self.button.as_mut().map(|control| {
+            control
+                .as_widget_mut()
+                .layout(&mut tree.children[0], renderer, &limits)
+        });
+
+        let actions = self.actions.as_mut().map(|actions| {
+            actions
+                .as_widget_mut()
+                .layout(&mut tree.children[2], renderer, &limits)
+        });
+
+        let side = control
+            .iter()
+            .chain(actions.iter())
+            .map(|node| node.size().width)
+            .fold(0.0, f32::max)
+            + PADDING * 2.0;
+
+        let title = self.title.as_widget_mut().layout(
+            &mut tree.children[1],
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new((size.width - side * 2.0).max(0.0), size.height),
+            ),
+        );
+
+        let place = |node: layout::Node, x: f32| {
+            let y = (size.height - node.size().height) / 2.0;
+
+            node.move_to(Point::new(x, y))
+        };
+
+        let start = |node: &layout::Node| match self.direction {
+            LayoutDirection::LeftToRight => PADDING,
+            LayoutDirection::RightToLeft => size.width - PADDING - node.size().width,
+        };
+
+        let end = |node: &layout::Node| match self.direction {
+            LayoutDirection::LeftToRight => size.width - PADDING - node.size().width,
+            LayoutDirection::RightToLeft => PADDING,
+        };
+
+        let control = control
+            .map(|node| {
+                let x = start(&node);
+                place(node, x)
+            })
+            .unwrap_or_else(|| layout::Node::new(Size::ZERO));
+
+        let actions = actions
+            .map(|node| {
+                let x = end(&node);
+                place(node, x)
+            })
+            .unwrap_or_else(|| layout::Node::new(Size::ZERO));
+
+        let title_x = (size.width - title.size().width) / 2.0;
+
+        layout::Node::with_children(size, vec![control, place(title, title_x), actions])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let children_layout: Vec<_> = layout.children().collect();
+
+        operation.traverse(&mut |operation| {
+            self.title.as_widget_mut().operate(
+                &mut tree.children[1],
+                children_layout[1],
+                renderer,
+                operation,
+            );
+
+            if let Some(actions) = self.actions.as_mut() {
+                actions.as_widget_mut().operate(
+                    &mut tree.children[2],
+                    children_layout[2],
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let children_layout: Vec<_> = layout.children().collect();
+
+        if let Some(control) = self.button.as_mut() {
+            let mut actions = Vec::new();
+            let mut control_shell = Shell::new(&mut actions);
+
+            control.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                children_layout[0],
+                cursor,
+                renderer,
+                clipboard,
+                &mut control_shell,
+                viewport,
+            );
+
+            if control_shell.is_event_captured() {
+                shell.capture_event();
+            }
+
+            if control_shell.is_layout_invalid() {
+                shell.invalidate_layout();
+            }
+
+            if control_shell.are_widgets_invalid() {
+                shell.invalidate_widgets();
+            }
+
+            shell.request_redraw_at(control_shell.redraw_request());
+
+            if let Some(action) = actions.pop() {
+                tree.state.downcast_mut::<State>().action = Some(action);
+            }
+        }
+
+        self.title.as_widget_mut().update(
+            &mut tree.children[1],
+            event,
+            children_layout[1],
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if let Some(actions) = self.actions.as_mut() {
+            actions.as_widget_mut().update(
+                &mut tree.children[2],
+                event,
+                children_layout[2],
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let children_layout: Vec<_> = layout.children().collect();
+
+        let control = self.button.as_ref().map(|control| {
+            control.as_widget().mouse_interaction(
+                &tree.children[0],
+                children_layout[0],
+                cursor,
+                viewport,
+                renderer,
+            )
+        });
+
+        let actions = self.actions.as_ref().map(|actions| {
+            actions.as_widget().mouse_interaction(
+                &tree.children[2],
+                children_layout[2],
+                cursor,
+                viewport,
+                renderer,
+            )
+        });
+
+        control.into_iter().chain(actions).max().unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let palette = theme.extended_palette();
+        let children_layout: Vec<_> = layout.children().collect();
+        let opacity = style.text_color.a;
+
+        let style = renderer::Style {
+            text_color: palette.primary.base.text.scale_alpha(opacity),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds: layout.bounds(),
+                ..Default::default()
+            },
+            palette.primary.base.color.scale_alpha(opacity),
+        );
+
+        if let Some(control) = self.control {
+            control_button::<Renderer>(control, self.direction, opacity)
+                .as_widget()
+                .draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    &style,
+                    children_layout[0],
+                    cursor,
+                    viewport,
+                );
+        }
+
+        self.title.as_widget().draw(
+            &tree.children[1],
+            renderer,
+            theme,
+            &style,
+            children_layout[1],
+            cursor,
+            viewport,
+        );
+
+        if let Some(actions) = self.actions.as_ref() {
+            actions.as_widget().draw(
+                &tree.children[2],
+                renderer,
+                theme,
+                &style,
+                children_layout[2],
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let actions = self.actions.as_mut()?;
+
+        actions.as_widget_mut().overlay(
+            &mut tree.children[2],
+            layout.child(2),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<HeaderBar<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(header: HeaderBar<'a, Message, Renderer>) -> Self {
+        Self::new(header)
+    }
+}
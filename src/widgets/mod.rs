@@ -2,10 +2,14 @@ use iced_core::Element;
 
 #[cfg(feature = "drawer")]
 pub mod drawer_navigator;
+#[cfg(any(feature = "stack", feature = "drawer"))]
+pub mod header;
 pub mod operations;
 #[cfg(feature = "stack")]
 pub mod stack_navigator;
 #[cfg(feature = "tabs")]
+pub mod tab_bar;
+#[cfg(feature = "tabs")]
 pub mod tabs_navigator;
 
 #[allow(dead_code)]
@@ -28,6 +32,27 @@ impl<'a, Params, Message, Theme, Renderer>
     }
 }
 
+#[cfg(all(test, any(feature = "stack", feature = "tabs")))]
+pub(crate) mod testing {
+    use iced_core::widget::Tree;
+    use iced_core::{Element, Theme};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub(crate) enum Page {
+        Home,
+        List,
+        Settings,
+    }
+
+    pub(crate) fn mount(element: impl Into<Element<'static, (), Theme, ()>>) -> Tree {
+        Tree::new(element.into())
+    }
+
+    pub(crate) fn state<State: 'static>(tree: &mut Tree) -> (&mut State, &mut Vec<Tree>) {
+        (tree.state.downcast_mut::<State>(), &mut tree.children)
+    }
+}
+
 pub(crate) trait NavigatorState {
     type Key;
 
@@ -2,6 +2,11 @@ use iced_core::{
     Rectangle,
     widget::{Id, Operation},
 };
+#[cfg(feature = "tabs")]
+use iced_core::{
+    Vector,
+    widget::operation::scrollable::{RelativeOffset, Scrollable},
+};
 use iced_runtime::{Task, task::widget as operate};
 use std::{hash::Hash, marker::PhantomData};
 
@@ -317,6 +322,175 @@ where
     }
 }
 
+#[cfg(feature = "tabs")]
+fn close_tab_op<T, Key>(page: Key, target: Option<Id>) -> impl Operation<T>
+where
+    Key: 'static + Eq + Hash + Clone + Send,
+{
+    struct CloseTab<Key> {
+        target: Option<Id>,
+        page: Option<Key>,
+    }
+
+    impl<T, Key> Operation<T> for CloseTab<Key>
+    where
+        Key: 'static + Eq + Hash + Clone + Send,
+    {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self)
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn std::any::Any) {
+            if let Some(value) = state.downcast_mut::<widgets::tabs_navigator::State<Key>>() {
+                value.request_update();
+
+                if id.is_some_and(|id| self.target.as_ref().is_some_and(|target| target != id)) {
+                    return;
+                }
+
+                if let Some(page) = self.page.take() {
+                    value.close_tab(page);
+                }
+            }
+        }
+    }
+
+    CloseTab {
+        target,
+        page: Some(page),
+    }
+}
+
+#[cfg(feature = "tabs")]
+fn move_tab_op<T, Key>(page: Key, index: usize, target: Option<Id>) -> impl Operation<T>
+where
+    Key: 'static + Eq + Hash + Clone + Send,
+{
+    struct MoveTab<Key> {
+        target: Option<Id>,
+        page: Option<Key>,
+        index: usize,
+    }
+
+    impl<T, Key> Operation<T> for MoveTab<Key>
+    where
+        Key: 'static + Eq + Hash + Clone + Send,
+    {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self)
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn std::any::Any) {
+            if let Some(value) = state.downcast_mut::<widgets::tabs_navigator::State<Key>>() {
+                value.request_update();
+
+                if id.is_some_and(|id| self.target.as_ref().is_some_and(|target| target != id)) {
+                    return;
+                }
+
+                if let Some(page) = self.page.take() {
+                    value.move_tab(page, self.index);
+                }
+            }
+        }
+    }
+
+    MoveTab {
+        target,
+        page: Some(page),
+        index,
+    }
+}
+
+#[cfg(feature = "stack")]
+#[derive(Debug, Default)]
+pub(crate) struct RootRequest {
+    pub(crate) pending: bool,
+}
+
+#[cfg(feature = "stack")]
+fn pop_to_root_op<T, Key>(target: Option<Id>) -> impl Operation<T>
+where
+    Key: 'static + Eq + Hash + Clone + Send,
+{
+    struct PopToRoot<Key> {
+        target: Option<Id>,
+        p: PhantomData<Key>,
+    }
+
+    impl<T, Key> Operation<T> for PopToRoot<Key>
+    where
+        Key: 'static + Eq + Hash + Clone + Send,
+    {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self)
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn std::any::Any) {
+            if let Some(value) = state.downcast_mut::<widgets::stack_navigator::State<Key>>() {
+                value.request_update();
+
+                if id.is_some_and(|id| self.target.as_ref().is_some_and(|target| target != id)) {
+                    return;
+                }
+
+                value.pop_to_root();
+            }
+        }
+    }
+
+    PopToRoot {
+        target,
+        p: PhantomData::<Key>,
+    }
+}
+
+#[cfg(all(feature = "stack", feature = "tabs"))]
+pub(crate) fn request_root() -> impl Operation {
+    struct RequestRoot;
+
+    impl Operation for RequestRoot {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self)
+        }
+
+        fn custom(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn std::any::Any) {
+            if let Some(request) = state.downcast_mut::<RootRequest>() {
+                request.pending = true;
+            }
+        }
+    }
+
+    RequestRoot
+}
+
+#[cfg(feature = "tabs")]
+pub(crate) fn scroll_to_top() -> impl Operation {
+    struct ScrollToTop;
+
+    impl Operation for ScrollToTop {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self)
+        }
+
+        fn scrollable(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+            _translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            state.snap_to(RelativeOffset {
+                x: None,
+                y: Some(0.0),
+            });
+        }
+    }
+
+    ScrollToTop
+}
+
 #[cfg(feature = "stack")]
 fn push_op<T, Key>(page: Key, target: Option<Id>) -> impl Operation<T>
 where
@@ -471,3 +645,75 @@ where
 {
     operate(push_op::<T, P>(page, Some(target)))
 }
+
+#[cfg(feature = "tabs")]
+pub fn open_tab<T, P>(page: P) -> Task<T>
+where
+    P: 'static + Eq + Hash + Clone + Send,
+    T: 'static + Send,
+{
+    operate(navigate_op::<T, P>(page, None))
+}
+
+#[cfg(feature = "tabs")]
+pub fn open_tab_by_id<T, P>(page: P, target: Id) -> Task<T>
+where
+    P: 'static + Eq + Hash + Clone + Send,
+    T: 'static + Send,
+{
+    operate(navigate_op::<T, P>(page, Some(target)))
+}
+
+#[cfg(feature = "tabs")]
+pub fn close_tab<T, P>(page: P) -> Task<T>
+where
+    P: 'static + Eq + Hash + Clone + Send,
+    T: 'static + Send,
+{
+    operate(close_tab_op::<T, P>(page, None))
+}
+
+#[cfg(feature = "tabs")]
+pub fn close_tab_by_id<T, P>(page: P, target: Id) -> Task<T>
+where
+    P: 'static + Eq + Hash + Clone + Send,
+    T: 'static + Send,
+{
+    operate(close_tab_op::<T, P>(page, Some(target)))
+}
+
+#[cfg(feature = "tabs")]
+pub fn move_tab<T, P>(page: P, index: usize) -> Task<T>
+where
+    P: 'static + Eq + Hash + Clone + Send,
+    T: 'static + Send,
+{
+    operate(move_tab_op::<T, P>(page, index, None))
+}
+
+#[cfg(feature = "tabs")]
+pub fn move_tab_by_id<T, P>(page: P, index: usize, target: Id) -> Task<T>
+where
+    P: 'static + Eq + Hash + Clone + Send,
+    T: 'static + Send,
+{
+    operate(move_tab_op::<T, P>(page, index, Some(target)))
+}
+
+#[cfg(feature = "stack")]
+pub fn pop_to_root<T, P>() -> Task<T>
+where
+    P: 'static + Eq + Hash + Clone + Send,
+    T: 'static + Send,
+{
+    operate(pop_to_root_op::<T, P>(None))
+}
+
+#[cfg(feature = "stack")]
+pub fn pop_to_root_by_id<T, P>(target: Id) -> Task<T>
+where
+    P: 'static + Eq + Hash + Clone + Send,
+    T: 'static + Send,
+{
+    operate(pop_to_root_op::<T, P>(Some(target)))
+}
@@ -1,17 +1,22 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::hash::Hash;
 use std::mem::Discriminant;
 use std::ops::{Div, Neg};
 
 use iced_core::window;
 use iced_core::{
-    Clipboard, Element, Event, Layout, Length, Padding, Rectangle, Shell, Size, Theme, Vector,
-    Widget, layout, mouse, overlay, renderer,
+    Clipboard, Color, Element, Event, Layout, Length, Padding, Rectangle, Shadow, Shell, Size,
+    Theme, Vector, Widget, layout, mouse, overlay,
+    renderer::{self, Quad},
+    text,
     widget::{Id, Operation, Tree, tree},
 };
 use iced_widget::container::{self, draw_background};
 
-use crate::animation::Frame;
+use crate::animation::{Animation, Animator, Easing, Frame, Spring, SpringFrame, easing};
+use crate::direction::{self, LayoutDirection};
+use crate::widgets::header::{self, Control, DefaultHeader, HeaderView};
+use crate::widgets::operations::RootRequest;
 use crate::widgets::{NavigatorElement, NavigatorElementSource, NavigatorState};
 
 type HeaderBuilder<'a, Key, Message, Theme, Renderer> =
@@ -19,6 +24,8 @@ type HeaderBuilder<'a, Key, Message, Theme, Renderer> =
 
 type OnNavigationEnd<'a, Key, Message> = dyn Fn(Option<Key>, Key) -> Message + 'a;
 
+type OnPageRestored<'a, Key, Message> = dyn Fn(Key) -> Message + 'a;
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum Action {
     NavigateFoward,
@@ -28,24 +35,220 @@ pub(crate) enum Action {
     ClearHistory,
 }
 
+#[derive(Debug, Clone)]
+pub(crate) enum Queued<Key> {
+    Navigate(Key),
+    Push(Key),
+    GoBack,
+    PopToRoot,
+    PopHistory,
+    ClearHistory,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Settled<Key> {
+    pub(crate) previous: Option<Key>,
+    pub(crate) current: Key,
+    pub(crate) restored: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct State<Key: Eq + Hash> {
     pub(crate) previous_page: Option<Key>,
     pub(crate) history: Vec<Key>,
     pub(crate) transition: Option<Transition>,
-    pub(crate) frame: Option<Frame>,
+    pub(crate) frame: Option<Animator>,
+    pub(crate) reversed: bool,
     pub(crate) pending_update: bool,
     pub(crate) current_action: Option<Action>,
+    pub(crate) dropped: usize,
+    pub(crate) queue: VecDeque<Queued<Key>>,
+    pub(crate) settled: Vec<Settled<Key>>,
+    pub(crate) concurrency: Concurrency,
+    pub(crate) retention: RetentionPolicy,
+    pub(crate) transition_duration: f32,
+    pub(crate) easing: Easing,
+    pub(crate) spring: Option<Spring>,
 }
 
 impl<Key: 'static + Eq + Hash + Clone> State<Key> {
+    fn new_frame(&self) -> Animator {
+        match self.spring {
+            Some(spring) => SpringFrame::new(spring).into(),
+            None => Frame::new()
+                .duration(self.transition_duration)
+                .map(self.easing)
+                .into(),
+        }
+    }
+
     pub(crate) fn push(&mut self, page: Key) {
+        if self.frame.is_some() {
+            self.queue.push_back(Queued::Push(page));
+            return;
+        }
+
         self.history.push(page);
-        self.frame = Some(Frame::new());
+        self.frame = Some(self.new_frame());
         self.transition = Some(Transition::Foward);
         self.previous_page = None;
         self.current_action = Some(Action::NavigateFoward);
     }
+
+    pub(crate) fn pop_to_root(&mut self) {
+        if self.frame.is_some() {
+            self.queue.push_back(Queued::PopToRoot);
+            return;
+        }
+
+        if self.history.len() < 2 {
+            return;
+        }
+
+        self.previous_page = Some(self.history.remove(self.history.len() - 1));
+        self.history.truncate(1);
+        self.dropped = 0;
+        self.frame = Some(self.new_frame());
+        self.transition = Some(Transition::Back);
+        self.current_action = Some(Action::NavigateTo(0));
+    }
+
+    fn run(&mut self, queued: Queued<Key>) {
+        match queued {
+            Queued::Navigate(page) => self.navigate(page),
+            Queued::Push(page) => self.push(page),
+            Queued::GoBack => self.go_back(),
+            Queued::PopToRoot => self.pop_to_root(),
+            Queued::PopHistory => self.pop_history(),
+            Queued::ClearHistory => self.clear_history(),
+        }
+    }
+
+    pub(crate) fn progress(&self) -> Option<f32> {
+        let value = self.frame.as_ref()?.get_value();
+
+        Some(if self.reversed { 100.0 - value } else { value })
+    }
+
+    pub(crate) fn reverse(&mut self) -> bool {
+        let Some(Transition::Foward) = self.transition else {
+            return false;
+        };
+
+        let covered = self.history[..self.history.len() - 1].to_vec();
+        let (mut history, target) = if self.reversed {
+            (covered, self.history.clone())
+        } else {
+            (self.history.clone(), covered)
+        };
+
+        for queued in &self.queue {
+            simulate(&mut history, queued);
+        }
+
+        if history != target {
+            return false;
+        }
+
+        if let Some(frame) = self.frame.as_mut() {
+            frame.reverse();
+        }
+
+        self.queue.clear();
+        self.reversed = !self.reversed;
+
+        true
+    }
+
+    pub(crate) fn undo(&mut self, children: &mut Vec<Tree>) {
+        self.reversed = false;
+        self.finish(children);
+        self.go_back();
+
+        if let Some(action) = self.current_action.take() {
+            apply_action(children, action);
+        }
+
+        self.settle(children);
+    }
+
+    pub(crate) fn settle(&mut self, children: &mut Vec<Tree>) {
+        let previous = self.finish(children);
+
+        if let Some(current) = self.history.last().cloned() {
+            let restored = self.apply_retention(children, self.retention);
+
+            self.settled.push(Settled {
+                previous,
+                current,
+                restored,
+            });
+        }
+    }
+
+    fn finish(&mut self, children: &mut Vec<Tree>) -> Option<Key> {
+        self.frame = None;
+        self.transition = None;
+
+        let Some(previous) = self.previous_page.clone() else {
+            return self.get_previous_key().cloned();
+        };
+
+        children.remove(children.len() - 3);
+
+        if children.len() == 3 {
+            children.remove(0);
+        } else if children.len() > 3 {
+            let len = children.len();
+
+            children.swap(len - 3, len - 4);
+        }
+
+        Some(previous)
+    }
+
+    pub(crate) fn replay(&mut self, children: &mut Vec<Tree>) {
+        while let Some(queued) = self.queue.pop_front() {
+            self.run(queued);
+
+            if let Some(action) = self.current_action.take() {
+                apply_action(children, action);
+            }
+
+            if self.frame.is_some() {
+                if self.concurrency == Concurrency::Coalesce && !self.queue.is_empty() {
+                    self.settle(children);
+                    continue;
+                }
+
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn apply_retention(
+        &mut self,
+        children: &mut [Tree],
+        policy: RetentionPolicy,
+    ) -> bool {
+        let len = self.history.len();
+        let top = len - 1;
+        let restored = top < self.dropped;
+
+        self.dropped = match policy {
+            RetentionPolicy::KeepAll => self.dropped.min(top),
+            RetentionPolicy::KeepTop(count) => {
+                self.dropped.min(top).max(len.saturating_sub(count.max(1)))
+            }
+            RetentionPolicy::DropCovered => top,
+        };
+
+        for index in 0..self.dropped {
+            children[tree_index(index, len, children.len())] = Tree::empty();
+        }
+
+        restored
+    }
 }
 
 impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
@@ -64,6 +267,11 @@ impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
     }
 
     fn navigate(&mut self, page: Key) {
+        if self.frame.is_some() {
+            self.queue.push_back(Queued::Navigate(page));
+            return;
+        }
+
         let disc = std::mem::discriminant(&page);
 
         let position = self
@@ -75,13 +283,13 @@ impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
             Some(index) => {
                 self.previous_page = Some(self.history.remove(self.history.len() - 1));
                 self.history.truncate(index + 1);
-                self.frame = Some(Frame::new());
+                self.frame = Some(self.new_frame());
                 self.transition = Some(Transition::Back);
                 self.current_action = Some(Action::NavigateTo(index));
             }
             None => {
                 self.history.push(page);
-                self.frame = Some(Frame::new());
+                self.frame = Some(self.new_frame());
                 self.transition = Some(Transition::Foward);
                 self.previous_page = None;
                 self.current_action = Some(Action::NavigateFoward);
@@ -90,32 +298,49 @@ impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
     }
 
     fn go_back(&mut self) {
+        if self.frame.is_some() {
+            self.queue.push_back(Queued::GoBack);
+            return;
+        }
+
         if self.history.is_empty() {
             return;
         }
 
         self.previous_page = Some(self.history.remove(self.history.len() - 1));
 
-        self.frame = Some(Frame::new());
+        self.frame = Some(self.new_frame());
         self.transition = Some(Transition::Back);
         self.current_action = Some(Action::NavigateBack);
     }
 
     fn clear_history(&mut self) {
+        if self.frame.is_some() {
+            self.queue.push_back(Queued::ClearHistory);
+            return;
+        }
+
         if let Some(item) = self.history.pop() {
             self.history.clear();
             self.history.push(item);
         }
 
+        self.dropped = 0;
         self.previous_page = None;
         self.current_action = Some(Action::ClearHistory);
     }
 
     fn pop_history(&mut self) {
+        if self.frame.is_some() {
+            self.queue.push_back(Queued::PopHistory);
+            return;
+        }
+
         let page_number = self.history.len();
 
         if page_number > 1 {
             self.history.remove(page_number - 2);
+            self.dropped = self.dropped.min(page_number - 2);
             self.previous_page = None;
         }
         self.current_action = Some(Action::PopHistory);
@@ -126,6 +351,50 @@ impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
 pub struct PageParams<Key> {
     pub page: Key,
     pub can_go_back: bool,
+    pub header_height: f32,
+}
+
+#[derive(Default)]
+pub enum PageHeader<'a, Key, Message, Renderer> {
+    #[default]
+    Default,
+    Hidden,
+    Custom(Box<HeaderBuilder<'a, Key, Message, Theme, Renderer>>),
+}
+
+impl<'a, Key, Message, Renderer> PageHeader<'a, Key, Message, Renderer> {
+    pub fn custom(
+        fun: impl Fn(PageParams<Key>) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self::Custom(Box::new(fun))
+    }
+}
+
+#[derive(Default)]
+pub struct PageOptions<'a, Key, Message, Renderer> {
+    pub header: PageHeader<'a, Key, Message, Renderer>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeaderMode {
+    #[default]
+    Screen,
+    Persistent,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Concurrency {
+    #[default]
+    Queue,
+    Coalesce,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RetentionPolicy {
+    #[default]
+    KeepAll,
+    KeepTop(usize),
+    DropCovered,
 }
 
 #[derive(Debug, Clone)]
@@ -145,9 +414,22 @@ where
     children:
         HashMap<Discriminant<Key>, NavigatorElement<'a, PageParams<Key>, Message, Theme, Renderer>>,
     header_builder: Option<Box<HeaderBuilder<'a, Key, Message, Theme, Renderer>>>,
+    default_header: Option<Box<HeaderView<'a, Key, Message, Renderer>>>,
+    page_options: HashMap<Discriminant<Key>, PageOptions<'a, Key, Message, Renderer>>,
     main_header: NavigatorElement<'a, PageParams<Key>, Message, Theme, Renderer>,
     secondary_header: NavigatorElement<'a, PageParams<Key>, Message, Theme, Renderer>,
     on_navigation_end: Option<Box<OnNavigationEnd<'a, Key, Message>>>,
+    on_page_restored: Option<Box<OnPageRestored<'a, Key, Message>>>,
+    retention: RetentionPolicy,
+    concurrency: Concurrency,
+    transition_duration: f32,
+    easing: Easing,
+    spring: Option<Spring>,
+    direction: Option<LayoutDirection>,
+    header_mode: HeaderMode,
+    floating_header: bool,
+    covered_dim: f32,
+    edge_shadow: bool,
 }
 
 impl<'a, Key, Message, Renderer> StackNavigator<'a, Key, Message, Renderer>
@@ -164,7 +446,20 @@ where
             secondary_header: NavigatorElement::empty(),
             home_page,
             header_builder: None,
+            default_header: None,
+            page_options: HashMap::new(),
             on_navigation_end: None,
+            on_page_restored: None,
+            retention: RetentionPolicy::KeepAll,
+            concurrency: Concurrency::Queue,
+            transition_duration: 0.3,
+            easing: easing::linear,
+            spring: None,
+            direction: None,
+            header_mode: HeaderMode::Screen,
+            floating_header: false,
+            covered_dim: 0.0,
+            edge_shadow: false,
         }
     }
 
@@ -208,6 +503,29 @@ where
         self
     }
 
+    pub fn insert_page_with_options(
+        mut self,
+        key: Key,
+        page: impl Into<Element<'a, Message, Theme, Renderer>>,
+        options: PageOptions<'a, Key, Message, Renderer>,
+    ) -> Self {
+        let disc = std::mem::discriminant(&key);
+
+        self.page_options.insert(disc, options);
+        self.insert_page(key, page)
+    }
+
+    pub fn page_options(
+        mut self,
+        key: Key,
+        options: PageOptions<'a, Key, Message, Renderer>,
+    ) -> Self {
+        self.page_options
+            .insert(std::mem::discriminant(&key), options);
+
+        self
+    }
+
     pub fn header_widget(
         mut self,
         fun: impl Fn(PageParams<Key>) -> Element<'a, Message, Theme, Renderer> + 'a,
@@ -217,6 +535,41 @@ where
         self
     }
 
+    pub fn default_header(mut self, header: DefaultHeader<'a, Key, Message, Renderer>) -> Self
+    where
+        Key: 'a,
+        Message: 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        self.default_header = Some(header.into_view());
+
+        self
+    }
+
+    pub fn header_mode(mut self, mode: HeaderMode) -> Self {
+        self.header_mode = mode;
+
+        self
+    }
+
+    pub fn floating_header(mut self, floating: bool) -> Self {
+        self.floating_header = floating;
+
+        self
+    }
+
+    pub fn covered_dim(mut self, opacity: f32) -> Self {
+        self.covered_dim = opacity.clamp(0.0, 1.0);
+
+        self
+    }
+
+    pub fn edge_shadow(mut self, enabled: bool) -> Self {
+        self.edge_shadow = enabled;
+
+        self
+    }
+
     pub fn on_navigation_end(
         mut self,
         on_navigation_end: impl Fn(Option<Key>, Key) -> Message + 'a,
@@ -225,6 +578,63 @@ where
 
         self
     }
+
+    pub fn retention_policy(mut self, policy: RetentionPolicy) -> Self {
+        self.retention = policy;
+
+        self
+    }
+
+    pub fn concurrency(mut self, concurrency: Concurrency) -> Self {
+        self.concurrency = concurrency;
+
+        self
+    }
+
+    pub fn on_page_restored(mut self, on_page_restored: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_page_restored = Some(Box::new(on_page_restored));
+
+        self
+    }
+
+    pub fn transition_duration(mut self, duration: f32) -> Self {
+        self.transition_duration = duration;
+
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+
+        self
+    }
+
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.spring = Some(spring);
+
+        self
+    }
+
+    pub fn direction(mut self, direction: LayoutDirection) -> Self {
+        self.direction = Some(direction);
+
+        self
+    }
+
+    fn get_direction(&self) -> LayoutDirection {
+        self.direction.unwrap_or_else(direction::layout_direction)
+    }
+
+    fn page_cursor(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> mouse::Cursor {
+        if self.floating_header
+            && !self.main_header.is_empty()
+            && cursor.is_over(layout.child(0).bounds())
+        {
+            return cursor.levitate();
+        }
+
+        cursor
+    }
 }
 
 impl<'a, Key, Message, Renderer> Widget<Message, Theme, Renderer>
@@ -252,7 +662,16 @@ where
             history: vec![self.home_page.clone()],
             transition: None,
             frame: None,
+            reversed: false,
             current_action: None,
+            dropped: 0,
+            queue: VecDeque::new(),
+            settled: Vec::new(),
+            concurrency: self.concurrency,
+            retention: self.retention,
+            transition_duration: self.transition_duration,
+            easing: self.easing,
+            spring: self.spring,
         })
     }
 
@@ -337,54 +756,29 @@ where
 
         operation.custom(self.id.as_ref(), layout.bounds(), state);
 
-        if let Some(action) = state.current_action.take() {
-            match action {
-                Action::NavigateFoward => {
-                    if tree.children.len() == 2 {
-                        tree.children.push(Tree::empty());
-                        tree.children.push(Tree::empty());
-                        return;
-                    }
-
-                    tree.children.push(Tree::empty());
-                    let size = tree.children.len();
-
-                    tree.children.swap(size - 2, size - 3);
-                    tree.children.swap(size - 4, size - 5);
-                }
-                Action::NavigateTo(index) => {
-                    let size = tree.children.len();
+        let mut request = RootRequest::default();
 
-                    tree.children.swap(size - 1, size - 3);
-                    tree.children.swap(size - 2, size - 4);
+        operation.custom(self.id.as_ref(), layout.bounds(), &mut request);
 
-                    tree.children.swap(size - 1, index);
-                    tree.children.drain(index..(size - 4));
-                }
-                Action::NavigateBack => {
-                    let size = tree.children.len();
+        if request.pending {
+            state.request_update();
+            state.pop_to_root();
+        }
 
-                    tree.children.swap(size - 1, size - 3);
-                    tree.children.swap(size - 2, size - 4);
-                }
-                Action::PopHistory => {
-                    let children_len = tree.children.len();
-
-                    if children_len > 4 {
-                        tree.children.swap(children_len - 3, children_len - 5);
-                        tree.children.remove(children_len - 5);
-                    } else if children_len > 2 {
-                        tree.children.truncate(2);
-                    }
-                }
-                Action::ClearHistory => {
-                    tree.children.truncate(2);
-                }
-            };
+        if let Some(action) = state.current_action.take() {
+            apply_action(&mut tree.children, action);
         }
     }
 
-    fn diff(&self, _tree: &mut Tree) {}
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        state.concurrency = self.concurrency;
+        state.retention = self.retention;
+        state.transition_duration = self.transition_duration;
+        state.easing = self.easing;
+        state.spring = self.spring;
+    }
 
     fn layout(
         &mut self,
@@ -394,11 +788,7 @@ where
     ) -> layout::Node {
         let limits = limits.width(self.width).height(self.height);
         let state = tree.state.downcast_ref::<State<Key>>();
-
-        if self.header_builder.is_none() {
-            self.main_header.clear_cache();
-            self.secondary_header.clear_cache();
-        }
+        let direction = self.get_direction();
 
         let base_layer = if let Some(transition) = state.transition.as_ref()
             && let Some(key) = state.get_previous_key()
@@ -409,22 +799,36 @@ where
             let disc = std::mem::discriminant(key);
             let children = &mut tree.children;
 
-            let params = PageParams {
+            let mut params = PageParams {
                 page: key.clone(),
                 can_go_back: match transition {
                     Transition::Foward => state.history.len() > 2,
                     Transition::Back => true,
                 },
+                header_height: 0.0,
             };
 
             self.children.get_mut(&disc).map(|page| {
-                let page_header = self.header_builder.as_ref().map(|builder| {
-                    let element = builder(params.clone());
+                let mut page_header = build_header(
+                    &self.page_options,
+                    &self.header_builder,
+                    &self.default_header,
+                    direction,
+                    &params,
+                )
+                .inspect(|element| {
+                    children[header_index].diff(element);
+                });
 
-                    children[header_index].diff(&element);
+                let header_node = floating_header_layout(
+                    self.floating_header,
+                    page_header.as_mut(),
+                    &mut children[header_index],
+                    &limits,
+                    renderer,
+                );
 
-                    element
-                });
+                params.header_height = header_node.as_ref().map_or(0.0, |node| node.size().height);
 
                 if page.is_empty() {
                     page.update_cache(params);
@@ -440,6 +844,7 @@ where
                     header,
                 } = resolve_page_layout(
                     page_header,
+                    header_node,
                     page_element,
                     header_index,
                     page_index,
@@ -450,8 +855,9 @@ where
                     renderer,
                 );
 
-                if let Some(header) = header {
-                    self.secondary_header.return_element(header);
+                match header {
+                    Some(header) => self.secondary_header.return_element(header),
+                    None => self.secondary_header.clear_cache(),
                 }
 
                 page.return_element(page_element);
@@ -479,21 +885,36 @@ where
             let disc = std::mem::discriminant(key);
             let children = &mut tree.children;
 
-            let params = PageParams {
+            let mut params = PageParams {
                 page: key.clone(),
                 can_go_back: state.history.len() > 1,
+                header_height: 0.0,
             };
 
             self.children
                 .get_mut(&disc)
                 .map(|page| {
-                    let page_header = self.header_builder.as_ref().map(|builder| {
-                        let element = builder(params.clone());
+                    let mut page_header = build_header(
+                        &self.page_options,
+                        &self.header_builder,
+                        &self.default_header,
+                        direction,
+                        &params,
+                    )
+                    .inspect(|element| {
+                        children[header_index].diff(element);
+                    });
 
-                        children[header_index].diff(&element);
+                    let header_node = floating_header_layout(
+                        self.floating_header,
+                        page_header.as_mut(),
+                        &mut children[header_index],
+                        &limits,
+                        renderer,
+                    );
 
-                        element
-                    });
+                    params.header_height =
+                        header_node.as_ref().map_or(0.0, |node| node.size().height);
 
                     if page.is_empty() {
                         page.update_cache(params);
@@ -509,6 +930,7 @@ where
                         header,
                     } = resolve_page_layout(
                         page_header,
+                        header_node,
                         page_element,
                         header_index,
                         page_index,
@@ -519,8 +941,9 @@ where
                         renderer,
                     );
 
-                    if let Some(header) = header {
-                        self.main_header.return_element(header);
+                    match header {
+                        Some(header) => self.main_header.return_element(header),
+                        None => self.main_header.clear_cache(),
                     }
 
                     page.return_element(page_element);
@@ -561,39 +984,53 @@ where
                 return;
             }
 
-            if let Some(frame) = state.frame.as_mut() {
-                if frame.is_complete() {
-                    state.frame = None;
-                    state.transition = None;
+            if state.frame.is_some() {
+                let interrupted =
+                    state.concurrency == Concurrency::Coalesce && !state.queue.is_empty();
+
+                if interrupted && state.reverse() {
+                    shell.request_redraw();
+                    return;
+                }
+
+                let complete = state.frame.as_ref().is_some_and(Animation::is_complete);
+
+                if complete || interrupted {
+                    if state.reversed {
+                        state.undo(&mut tree.children);
+                    } else {
+                        state.settle(&mut tree.children);
+                    }
 
-                    if let Some(previous) = state.previous_page.as_ref() {
-                        tree.children.remove(tree.children.len() - 3);
+                    state.replay(&mut tree.children);
 
-                        if tree.children.len() == 3 {
-                            tree.children.remove(0);
-                        } else if tree.children.len() > 3 {
-                            let len = tree.children.len();
+                    let retained: Vec<_> = state.history[state.dropped..]
+                        .iter()
+                        .map(std::mem::discriminant)
+                        .collect();
 
-                            tree.children.swap(len - 3, len - 4);
+                    for (disc, page) in self.children.iter_mut() {
+                        if !retained.contains(disc) {
+                            page.clear_cache();
                         }
+                    }
 
-                        shell.invalidate_layout();
-                        shell.request_redraw();
+                    for settled in state.settled.drain(..) {
+                        if settled.restored
+                            && let Some(on_page_restored) = self.on_page_restored.as_ref()
+                        {
+                            shell.publish(on_page_restored(settled.current.clone()));
+                        }
 
                         if let Some(on_navigation_end) = self.on_navigation_end.as_ref() {
-                            shell.publish(on_navigation_end(
-                                Some(previous.clone()),
-                                state.history.last().cloned().unwrap(),
-                            ));
+                            shell.publish(on_navigation_end(settled.previous, settled.current));
                         }
-                        return;
-                    } else if let Some(on_navigation_end) = self.on_navigation_end.as_ref() {
-                        shell.publish(on_navigation_end(
-                            state.get_previous_key().cloned(),
-                            state.history.last().cloned().unwrap(),
-                        ));
                     }
-                } else {
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                    return;
+                } else if let Some(frame) = state.frame.as_mut() {
                     frame.update()
                 }
 
@@ -620,10 +1057,25 @@ where
                 shell,
                 viewport,
             );
+
+            if let Some(Control::Back) = header::take_action(&mut tree.children[header_index])
+                && state.history.len() > 1
+            {
+                state.go_back();
+
+                if let Some(action) = state.current_action.take() {
+                    apply_action(&mut tree.children, action);
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                return;
+            }
         }
 
         let key = state.history.last().unwrap();
         let disc = std::mem::discriminant(key);
+        let page_cursor = self.page_cursor(layout, cursor);
 
         if let Some(page) = self.children.get_mut(&disc) {
             let element = page.get_element_mut().unwrap();
@@ -633,7 +1085,7 @@ where
                 widget_state,
                 event,
                 layout.child(if self.main_header.is_empty() { 0 } else { 1 }),
-                cursor,
+                page_cursor,
                 renderer,
                 clipboard,
                 shell,
@@ -663,15 +1115,19 @@ where
 
         let layout = layout.children().last().unwrap();
 
-        let header_interaction = self.main_header.get_element().map(|header| {
-            header.as_widget().mouse_interaction(
-                header_state,
-                layout.child(0),
-                cursor,
-                viewport,
-                renderer,
-            )
-        });
+        let header_interaction = self
+            .main_header
+            .get_element()
+            .filter(|_| cursor.is_over(layout.child(0).bounds()))
+            .map(|header| {
+                header.as_widget().mouse_interaction(
+                    header_state,
+                    layout.child(0),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            });
 
         header_interaction.unwrap_or_else(|| {
             let key = state.history.last().unwrap();
@@ -685,7 +1141,7 @@ where
                     element.as_widget().mouse_interaction(
                         page_state,
                         layout.child(if self.main_header.is_empty() { 0 } else { 1 }),
-                        cursor,
+                        self.page_cursor(layout, cursor),
                         viewport,
                         renderer,
                     )
@@ -711,105 +1167,129 @@ where
         let bounds = layout.bounds();
         let nav_state = tree.state.downcast_ref::<State<Key>>();
 
-        if let Some(mut clipped_viewport) = bounds.intersection(viewport) {
+        if let Some(clipped_viewport) = bounds.intersection(viewport) {
             let children_layout: Vec<_> = layout.children().collect();
             let children_len = tree.children.len();
 
             let (main_transition, base_transition) = nav_state
                 .transition
                 .as_ref()
-                .map(|transition| transition.to_translation(nav_state.frame.as_ref(), &bounds))
+                .map(|transition| {
+                    transition.to_translation(nav_state.progress(), &bounds, self.get_direction())
+                })
                 .unwrap_or((None, None));
 
+            let progress = nav_state
+                .transition
+                .as_ref()
+                .and(nav_state.progress())
+                .map(|progress| progress.div(100.0).clamp(0.0, 1.0));
+
+            let header_fade = match self.header_mode {
+                HeaderMode::Persistent if nav_state.transition.is_some() => {
+                    nav_state.progress().map(|progress| progress.div(100.0))
+                }
+                _ => None,
+            };
+
             if nav_state.transition.is_some()
                 && let Some(key) = nav_state.get_previous_key()
             {
-                let mut clipped_viewport = clipped_viewport;
                 let page_state = tree.children.get(children_len - 3).unwrap();
-                let page_layout = children_layout[0].children().collect::<Vec<_>>();
-
                 let disc = std::mem::discriminant(key);
 
                 if let Some(page) = self.children.get(&disc) {
-                    let element = page.get_element().unwrap();
-
-                    if let Some(element) = self.secondary_header.get_element() {
-                        let header_state = tree.children.get(children_len - 4).unwrap();
-
-                        let offset = page_layout[0].bounds().height;
+                    let header = self.secondary_header.get_element().map(|element| {
+                        let header_draw = match header_fade {
+                            Some(progress) => HeaderDraw::Faded(1.0 - progress),
+                            None => HeaderDraw::Translated,
+                        };
 
-                        draw_layer(
-                            base_transition,
+                        (
                             element,
-                            header_state,
-                            renderer,
-                            theme,
-                            style,
-                            page_layout[0],
-                            cursor,
-                            &clipped_viewport,
-                        );
-
-                        clipped_viewport.height -= offset;
-                        clipped_viewport.y += offset;
-                    }
+                            tree.children.get(children_len - 4).unwrap(),
+                            header_draw,
+                        )
+                    });
 
-                    draw_layer(
+                    draw_page_layer(
                         base_transition,
-                        element,
-                        page_state,
+                        header,
+                        (page.get_element().unwrap(), page_state),
+                        self.floating_header,
                         renderer,
                         theme,
                         style,
-                        *page_layout.last().unwrap(),
+                        children_layout[0],
                         cursor,
                         &clipped_viewport,
                     );
+
+                    if let Some(progress) = progress
+                        && self.covered_dim > 0.0
+                    {
+                        let bounds = children_layout[0].bounds()
+                            + Vector::new(base_transition.unwrap_or(0.0), 0.0);
+
+                        renderer.with_layer(clipped_viewport, |renderer| {
+                            renderer.fill_quad(
+                                Quad {
+                                    bounds,
+                                    ..Default::default()
+                                },
+                                shade_color(theme).scale_alpha(self.covered_dim * progress),
+                            );
+                        });
+                    }
                 }
             }
 
             let page_state = tree.children.get(children_len - 1).unwrap();
-            let page_layout = children_layout
-                .last()
-                .unwrap()
-                .children()
-                .collect::<Vec<_>>();
-
             let key = nav_state.history.last().unwrap();
             let disc = std::mem::discriminant(key);
 
             if let Some(page) = self.children.get(&disc) {
-                let element = page.get_element().unwrap();
+                let header = self.main_header.get_element().map(|element| {
+                    let header_draw = match header_fade {
+                        Some(progress) => HeaderDraw::Faded(progress),
+                        None => HeaderDraw::Translated,
+                    };
 
-                renderer.with_layer(clipped_viewport, |renderer| {
-                    if let Some(element) = self.main_header.get_element() {
-                        let header_state = tree.children.get(children_len - 2).unwrap();
-                        let offset = page_layout[0].bounds().height;
+                    (
+                        element,
+                        tree.children.get(children_len - 2).unwrap(),
+                        header_draw,
+                    )
+                });
 
-                        draw_layer(
-                            main_transition,
-                            element,
-                            header_state,
-                            renderer,
-                            theme,
-                            style,
-                            page_layout[0],
-                            cursor,
-                            &clipped_viewport,
+                renderer.with_layer(clipped_viewport, |renderer| {
+                    if progress.is_some() && self.edge_shadow {
+                        let bounds = children_layout.last().unwrap().bounds()
+                            + Vector::new(main_transition.unwrap_or(0.0), 0.0);
+
+                        renderer.fill_quad(
+                            Quad {
+                                bounds,
+                                shadow: Shadow {
+                                    color: shade_color(theme).scale_alpha(0.35),
+                                    offset: Vector::ZERO,
+                                    blur_radius: 16.0,
+                                },
+                                ..Default::default()
+                            },
+                            theme.palette().background,
                         );
-
-                        clipped_viewport.height -= offset;
-                        clipped_viewport.y += offset;
                     }
 
-                    draw_layer(
+                    draw_page_layer(
                         main_transition,
-                        element,
-                        page_state,
+                        header,
+                        (page.get_element().unwrap(), page_state),
+                        self.floating_header,
                         renderer,
                         theme,
                         style,
-                        *page_layout.last().unwrap(),
+                        *children_layout.last().unwrap(),
                         cursor,
                         &clipped_viewport,
                     );
@@ -832,7 +1312,9 @@ where
         let (main_transition, _base_transition) = nav_state
             .transition
             .as_ref()
-            .map(|transition| transition.to_translation(nav_state.frame.as_ref(), &bounds))
+            .map(|transition| {
+                transition.to_translation(nav_state.progress(), &bounds, self.get_direction())
+            })
             .unwrap_or((None, None));
 
         if let Some(mut clipped_viewport) = bounds.intersection(viewport) {
@@ -840,6 +1322,14 @@ where
                 return None;
             }
 
+            let header_translation = match self.header_mode {
+                HeaderMode::Persistent => translation,
+                HeaderMode::Screen => Vector {
+                    x: translation.x + main_transition.unwrap_or(0.0),
+                    y: translation.y,
+                },
+            };
+
             let translation = Vector {
                 x: translation.x + main_transition.unwrap_or(0.0),
                 y: translation.y,
@@ -869,11 +1359,13 @@ where
                         page_layout[0],
                         renderer,
                         &clipped_viewport,
-                        translation,
+                        header_translation,
                     );
 
-                    clipped_viewport.height -= offset;
-                    clipped_viewport.y += offset;
+                    if !self.floating_header {
+                        clipped_viewport.height -= offset;
+                        clipped_viewport.y += offset;
+                    }
 
                     overlay
                 });
@@ -901,6 +1393,7 @@ where
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn draw_layer<'a, Message, Renderer>(
     translation: Option<f32>,
     layer: &Element<'a, Message, Theme, Renderer>,
@@ -933,30 +1426,166 @@ fn draw_layer<'a, Message, Renderer>(
     };
 }
 
+fn shade_color(theme: &Theme) -> Color {
+    let palette = theme.extended_palette();
+
+    if palette.is_dark {
+        Color::BLACK
+    } else {
+        palette.background.base.text
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HeaderDraw {
+    Translated,
+    Faded(f32),
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_page_layer<'a, Message, Renderer>(
+    translation: Option<f32>,
+    header: Option<(&Element<'a, Message, Theme, Renderer>, &Tree, HeaderDraw)>,
+    page: (&Element<'a, Message, Theme, Renderer>, &Tree),
+    floating_header: bool,
+    renderer: &mut Renderer,
+    theme: &Theme,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+) where
+    Renderer: iced_core::Renderer,
+{
+    let header_layout = layout.child(0);
+    let page_layout = layout.children().last().unwrap();
+    let mut page_viewport = *viewport;
+
+    if header.is_some() && !floating_header {
+        let offset = header_layout.bounds().height;
+
+        page_viewport.height -= offset;
+        page_viewport.y += offset;
+    }
+
+    if floating_header {
+        draw_layer(
+            translation,
+            page.0,
+            page.1,
+            renderer,
+            theme,
+            style,
+            page_layout,
+            cursor,
+            &page_viewport,
+        );
+    }
+
+    if let Some((element, tree, header_draw)) = header {
+        match header_draw {
+            HeaderDraw::Faded(opacity) => draw_header(
+                opacity,
+                element,
+                tree,
+                renderer,
+                theme,
+                style,
+                header_layout,
+                cursor,
+                viewport,
+            ),
+            HeaderDraw::Translated if floating_header => renderer.with_translation(
+                Vector::new(translation.unwrap_or(0.0), 0.0),
+                |renderer| {
+                    element.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        header_layout,
+                        cursor,
+                        viewport,
+                    )
+                },
+            ),
+            HeaderDraw::Translated => draw_layer(
+                translation,
+                element,
+                tree,
+                renderer,
+                theme,
+                style,
+                header_layout,
+                cursor,
+                viewport,
+            ),
+        }
+    }
+
+    if !floating_header {
+        draw_layer(
+            translation,
+            page.0,
+            page.1,
+            renderer,
+            theme,
+            style,
+            page_layout,
+            cursor,
+            &page_viewport,
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_header<'a, Message, Renderer>(
+    opacity: f32,
+    header: &Element<'a, Message, Theme, Renderer>,
+    tree: &Tree,
+    renderer: &mut Renderer,
+    theme: &Theme,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+) where
+    Renderer: iced_core::Renderer,
+{
+    let style = renderer::Style {
+        text_color: style.text_color.scale_alpha(opacity.clamp(0.0, 1.0)),
+    };
+
+    header
+        .as_widget()
+        .draw(tree, renderer, theme, &style, layout, cursor, viewport);
+}
+
 impl Transition {
     fn to_translation(
         &self,
-        frame: Option<&Frame>,
+        progress: Option<f32>,
         area: &Rectangle,
+        direction: LayoutDirection,
     ) -> (Option<f32>, Option<f32>) {
         let width = area.width;
-        let frame = match frame {
+        let progress = match progress {
             Some(value) => value,
             None => return (None, None),
         };
 
         match self {
             Self::Foward => {
-                let main = (frame.get_value().div(100.0) - 1.0).abs() * width;
-                let base = ((frame.get_value() * 0.4).div(100.0) * width).neg();
+                let main = (progress.div(100.0) - 1.0).abs() * width;
+                let base = ((progress * 0.4).div(100.0) * width).neg();
 
-                (Some(main), Some(base))
+                (Some(direction.mirror(main)), Some(direction.mirror(base)))
             }
             Self::Back => {
-                let main = (frame.get_value().div(100.0) * width) - width;
-                let base = (frame.get_value().div(100.0) * 0.6) * width;
+                let main = (progress.div(100.0) * width) - width;
+                let base = (progress.div(100.0) * 0.6) * width;
 
-                (Some(main), Some(base))
+                (Some(direction.mirror(main)), Some(direction.mirror(base)))
             }
         }
     }
@@ -980,9 +1609,11 @@ struct LayoutResult<'a, Message, Theme, Renderer> {
     page: Element<'a, Message, Theme, Renderer>,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn resolve_page_layout<'a, Message, Theme, Renderer>(
     header: Option<Element<'a, Message, Theme, Renderer>>,
-    page: Element<'a, Message, Theme, Renderer>,
+    floating_header: Option<layout::Node>,
+    mut page: Element<'a, Message, Theme, Renderer>,
     header_index: usize,
     page_index: usize,
     width: Length,
@@ -994,6 +1625,21 @@ fn resolve_page_layout<'a, Message, Theme, Renderer>(
 where
     Renderer: iced_core::Renderer,
 {
+    if let Some(header_node) = floating_header {
+        let page_node = page
+            .as_widget_mut()
+            .layout(&mut children[page_index], renderer, limits);
+
+        return LayoutResult {
+            node: layout::Node::with_children(
+                limits.resolve(width, height, page_node.size()),
+                vec![header_node, page_node],
+            ),
+            header,
+            page,
+        };
+    }
+
     let mut elements = Vec::with_capacity(2);
 
     if let Some(header) = header {
@@ -1027,6 +1673,138 @@ where
     }
 }
 
+fn build_header<'a, Key, Message, Renderer>(
+    options: &HashMap<Discriminant<Key>, PageOptions<'a, Key, Message, Renderer>>,
+    header_builder: &Option<Box<HeaderBuilder<'a, Key, Message, Theme, Renderer>>>,
+    default_header: &Option<Box<HeaderView<'a, Key, Message, Renderer>>>,
+    direction: LayoutDirection,
+    params: &PageParams<Key>,
+) -> Option<Element<'a, Message, Theme, Renderer>>
+where
+    Key: Clone,
+{
+    let disc = std::mem::discriminant(&params.page);
+
+    match options.get(&disc).map(|options| &options.header) {
+        Some(PageHeader::Hidden) => None,
+        Some(PageHeader::Custom(builder)) => Some(builder(params.clone())),
+        Some(PageHeader::Default) | None => match header_builder {
+            Some(builder) => Some(builder(params.clone())),
+            None => default_header.as_ref().map(|view| {
+                view(
+                    &params.page,
+                    params.can_go_back.then_some(Control::Back),
+                    direction,
+                )
+            }),
+        },
+    }
+}
+
+fn simulate<Key: Eq + Hash + Clone>(history: &mut Vec<Key>, queued: &Queued<Key>) {
+    match queued {
+        Queued::Navigate(page) => {
+            let disc = std::mem::discriminant(page);
+
+            match history
+                .iter()
+                .rposition(|element| std::mem::discriminant(element) == disc)
+            {
+                Some(index) => {
+                    history.pop();
+                    history.truncate(index + 1);
+                }
+                None => history.push(page.clone()),
+            }
+        }
+        Queued::Push(page) => history.push(page.clone()),
+        Queued::GoBack => {
+            history.pop();
+        }
+        Queued::PopToRoot => history.truncate(1),
+        Queued::PopHistory if history.len() > 1 => {
+            history.remove(history.len() - 2);
+        }
+        Queued::PopHistory => {}
+        Queued::ClearHistory => {
+            let len = history.len();
+
+            history.drain(..len.saturating_sub(1));
+        }
+    }
+}
+
+fn tree_index(index: usize, history_len: usize, children_len: usize) -> usize {
+    match history_len - index {
+        1 => children_len - 1,
+        2 => children_len - 3,
+        _ => index,
+    }
+}
+
+fn apply_action(children: &mut Vec<Tree>, action: Action) {
+    match action {
+        Action::NavigateFoward => {
+            if children.len() == 2 {
+                children.push(Tree::empty());
+                children.push(Tree::empty());
+                return;
+            }
+
+            children.push(Tree::empty());
+            let size = children.len();
+
+            children.swap(size - 2, size - 3);
+            children.swap(size - 4, size - 5);
+        }
+        Action::NavigateTo(index) => {
+            let size = children.len();
+
+            children.swap(size - 1, size - 3);
+            children.swap(size - 2, size - 4);
+
+            children.swap(size - 1, index);
+            children.drain(index..(size - 4));
+        }
+        Action::NavigateBack => {
+            let size = children.len();
+
+            children.swap(size - 1, size - 3);
+            children.swap(size - 2, size - 4);
+        }
+        Action::PopHistory => {
+            let children_len = children.len();
+
+            if children_len > 4 {
+                children.swap(children_len - 3, children_len - 5);
+                children.remove(children_len - 5);
+            } else if children_len > 2 {
+                children.truncate(2);
+            }
+        }
+        Action::ClearHistory => {
+            children.truncate(2);
+        }
+    }
+}
+
+fn floating_header_layout<'a, Message, Theme, Renderer>(
+    floating: bool,
+    header: Option<&mut Element<'a, Message, Theme, Renderer>>,
+    tree: &mut Tree,
+    limits: &layout::Limits,
+    renderer: &Renderer,
+) -> Option<layout::Node>
+where
+    Renderer: iced_core::Renderer,
+{
+    if !floating {
+        return None;
+    }
+
+    header.map(|header| header.as_widget_mut().layout(tree, renderer, limits))
+}
+
 pub fn stack_navigator<'a, Key, Message, Renderer>(
     home_page: Key,
 ) -> StackNavigator<'a, Key, Message, Renderer>
@@ -1036,3 +1814,247 @@ where
 {
     StackNavigator::new(home_page)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::testing::{self, Page};
+
+    fn run(tree: &mut Tree, queued: Queued<Page>) {
+        let (state, children) = testing::state::<State<Page>>(tree);
+
+        state.run(queued);
+
+        if let Some(action) = state.current_action.take() {
+            apply_action(children, action);
+        }
+    }
+
+    fn coalesced() -> StackNavigator<'static, Page, (), ()> {
+        StackNavigator::new(Page::Home).concurrency(Concurrency::Coalesce)
+    }
+
+    fn settle(tree: &mut Tree) {
+        let (state, children) = testing::state::<State<Page>>(tree);
+
+        state.settle(children);
+    }
+
+    #[test]
+    fn reverse_runs_back_a_push_undone_by_the_queue() {
+        let mut tree = testing::mount(coalesced());
+
+        run(&mut tree, Queued::Push(Page::List));
+
+        let (state, children) = testing::state::<State<Page>>(&mut tree);
+
+        state.go_back();
+
+        assert!(state.reverse());
+        assert!(state.reversed);
+        assert!(state.queue.is_empty());
+
+        state.undo(children);
+
+        assert_eq!(state.history, vec![Page::Home]);
+        assert!(state.frame.is_none());
+        assert!(!state.reversed);
+        assert_eq!(children.len(), 2);
+    }
+
+    #[test]
+    fn reverse_ignores_queues_that_do_not_return() {
+        let mut tree = testing::mount(coalesced());
+
+        run(&mut tree, Queued::Push(Page::List));
+
+        let (state, _) = testing::state::<State<Page>>(&mut tree);
+
+        state.push(Page::Settings);
+
+        assert!(!state.reverse());
+        assert_eq!(state.queue.len(), 1);
+    }
+
+    #[test]
+    fn reverse_resumes_when_the_page_is_pushed_again() {
+        let mut tree = testing::mount(coalesced());
+
+        run(&mut tree, Queued::Push(Page::List));
+
+        let (state, _) = testing::state::<State<Page>>(&mut tree);
+
+        state.go_back();
+        state.reverse();
+        state.navigate(Page::List);
+
+        assert!(state.reverse());
+        assert!(!state.reversed);
+        assert_eq!(state.history, vec![Page::Home, Page::List]);
+    }
+
+    #[test]
+    fn to_translation_follows_reversed_progress() {
+        let mut tree = testing::mount(coalesced());
+        let (state, _) = testing::state::<State<Page>>(&mut tree);
+        let area = Rectangle::new(iced_core::Point::ORIGIN, Size::new(400.0, 800.0));
+        let translation = |state: &State<Page>, direction| {
+            Transition::Foward.to_translation(state.progress(), &area, direction)
+        };
+
+        state.frame = Some(Frame::new().duration(0.0).into());
+
+        assert_eq!(
+            translation(state, LayoutDirection::LeftToRight),
+            (Some(0.0), Some(-160.0))
+        );
+
+        state.reversed = true;
+
+        assert_eq!(
+            translation(state, LayoutDirection::LeftToRight),
+            (Some(400.0), Some(0.0))
+        );
+        assert_eq!(
+            translation(state, LayoutDirection::RightToLeft),
+            (Some(-400.0), Some(0.0))
+        );
+    }
+
+    #[test]
+    fn settle_reports_every_coalesced_transition() {
+        let mut tree = testing::mount(coalesced());
+
+        run(&mut tree, Queued::Push(Page::List));
+
+        let (state, children) = testing::state::<State<Page>>(&mut tree);
+
+        state.push(Page::Settings);
+        state.go_back();
+        state.settle(children);
+        state.replay(children);
+
+        let settled: Vec<_> = state
+            .settled
+            .iter()
+            .map(|settled| (settled.previous, settled.current))
+            .collect();
+
+        assert_eq!(
+            settled,
+            vec![
+                (Some(Page::Home), Page::List),
+                (Some(Page::List), Page::Settings),
+            ]
+        );
+        assert_eq!(state.history, vec![Page::Home, Page::List]);
+        assert!(state.frame.is_some());
+        assert!(state.queue.is_empty());
+    }
+
+    #[test]
+    fn apply_retention_keeps_top_pages() {
+        let mut tree = testing::mount(
+            StackNavigator::new(Page::Home).retention_policy(RetentionPolicy::KeepTop(2)),
+        );
+
+        run(&mut tree, Queued::Push(Page::List));
+        settle(&mut tree);
+        run(&mut tree, Queued::Push(Page::Settings));
+        settle(&mut tree);
+
+        assert_eq!(testing::state::<State<Page>>(&mut tree).0.dropped, 1);
+    }
+
+    #[test]
+    fn apply_retention_keeps_top_pages_across_pops() {
+        let mut tree = testing::mount(
+            StackNavigator::new(Page::Home).retention_policy(RetentionPolicy::KeepTop(2)),
+        );
+
+        let mark = |tree: &mut Tree| {
+            let (state, children) = testing::state::<State<Page>>(tree);
+            let page = *state.history.last().unwrap();
+
+            children.last_mut().unwrap().state = tree::State::new(page);
+        };
+
+        let pages = |tree: &mut Tree| {
+            let (state, children) = testing::state::<State<Page>>(tree);
+            let len = state.history.len();
+
+            (0..len)
+                .map(
+                    |index| match &children[tree_index(index, len, children.len())].state {
+                        tree::State::Some(state) => state.downcast_ref::<Page>().copied(),
+                        tree::State::None => None,
+                    },
+                )
+                .collect::<Vec<_>>()
+        };
+
+        mark(&mut tree);
+        run(&mut tree, Queued::Push(Page::List));
+        settle(&mut tree);
+        mark(&mut tree);
+        run(&mut tree, Queued::Push(Page::Settings));
+        settle(&mut tree);
+        mark(&mut tree);
+
+        assert_eq!(
+            pages(&mut tree),
+            vec![None, Some(Page::List), Some(Page::Settings)]
+        );
+
+        run(&mut tree, Queued::GoBack);
+        settle(&mut tree);
+
+        assert_eq!(pages(&mut tree), vec![None, Some(Page::List)]);
+
+        run(&mut tree, Queued::Push(Page::Settings));
+        settle(&mut tree);
+
+        assert_eq!(pages(&mut tree), vec![None, Some(Page::List), None]);
+    }
+
+    #[test]
+    fn apply_retention_drops_covered_pages() {
+        let mut tree = testing::mount(
+            StackNavigator::new(Page::Home).retention_policy(RetentionPolicy::DropCovered),
+        );
+
+        run(&mut tree, Queued::Push(Page::List));
+        settle(&mut tree);
+        run(&mut tree, Queued::Push(Page::Settings));
+        settle(&mut tree);
+
+        assert_eq!(testing::state::<State<Page>>(&mut tree).0.dropped, 2);
+
+        run(&mut tree, Queued::GoBack);
+        settle(&mut tree);
+
+        let (state, _) = testing::state::<State<Page>>(&mut tree);
+        let settled = state.settled.last().unwrap();
+
+        assert_eq!(settled.current, Page::List);
+        assert!(settled.restored);
+        assert_eq!(state.dropped, 1);
+    }
+
+    #[test]
+    fn apply_retention_keeps_all_pages_by_default() {
+        let mut tree = testing::mount(
+            StackNavigator::new(Page::Home).retention_policy(RetentionPolicy::KeepAll),
+        );
+
+        run(&mut tree, Queued::Push(Page::List));
+        settle(&mut tree);
+        run(&mut tree, Queued::GoBack);
+        settle(&mut tree);
+
+        let (state, _) = testing::state::<State<Page>>(&mut tree);
+
+        assert_eq!(state.dropped, 0);
+        assert!(state.settled.iter().all(|settled| !settled.restored));
+    }
+}
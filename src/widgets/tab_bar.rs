@@ -0,0 +1,731 @@
+use iced_core::{
+    Alignment, Border, Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+    Theme, Widget, layout, mouse,
+    renderer::{self, Quad},
+    text, touch,
+    widget::{Tree, tree},
+};
+use iced_widget::{Column, Text};
+
+use crate::direction::LayoutDirection;
+use crate::tabs_navigator::Mode;
+
+const HEIGHT: f32 = 64.0;
+const RAIL_WIDTH: f32 = 80.0;
+const INDICATOR: f32 = 3.0;
+const BADGE_PADDING: f32 = 4.0;
+const CLOSE_PADDING: f32 = 4.0;
+const DRAG_THRESHOLD: f32 = 6.0;
+const DROP_INDICATOR: f32 = 2.0;
+
+pub struct TabOptions<'a, Message, Renderer> {
+    pub label: String,
+    pub icon: Option<Element<'a, Message, Theme, Renderer>>,
+    pub badge: Option<String>,
+}
+
+impl<'a, Message, Renderer> TabOptions<'a, Message, Renderer> {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            badge: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.icon = Some(icon.into());
+
+        self
+    }
+
+    pub fn badge(mut self, badge: impl Into<String>) -> Self {
+        self.badge = Some(badge.into());
+
+        self
+    }
+
+    pub(crate) fn into_item(self) -> TabItem<'a, Message, Renderer>
+    where
+        Message: 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        TabItem {
+            content: Column::new()
+                .push(self.icon)
+                .push(Text::new(self.label).size(12))
+                .spacing(4)
+                .align_x(Alignment::Center)
+                .into(),
+            badge: self.badge.map(|badge| Text::new(badge).size(11).into()),
+            close: Some(Text::new("×").size(14).into()),
+            enabled: true,
+        }
+    }
+}
+
+pub(crate) struct TabItem<'a, Message, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    badge: Option<Element<'a, Message, Theme, Renderer>>,
+    close: Option<Element<'a, Message, Theme, Renderer>>,
+    enabled: bool,
+}
+
+impl<'a, Message, Renderer> TabItem<'a, Message, Renderer> {
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+
+        self
+    }
+
+    pub fn closable(mut self, closable: bool) -> Self {
+        if !closable {
+            self.close = None;
+        }
+
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    active: Option<usize>,
+    hovered: Option<(usize, bool)>,
+    pressed: Option<(usize, bool)>,
+    selection: Option<usize>,
+    close: Option<usize>,
+    order: Vec<usize>,
+    drag: Option<Drag>,
+    moved: Option<(usize, usize)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    item: usize,
+    origin: Point,
+    target: Option<usize>,
+}
+
+impl State {
+    fn slot(&self, index: usize) -> usize {
+        self.order
+            .iter()
+            .position(|item| *item == index)
+            .unwrap_or(index)
+    }
+}
+
+pub(crate) fn set_order(tree: &mut Tree, order: Vec<usize>) {
+    if tree.tag == tree::Tag::of::<State>() {
+        tree.state.downcast_mut::<State>().order = order;
+    }
+}
+
+pub(crate) fn take_move(tree: &mut Tree) -> Option<(usize, usize)> {
+    if tree.tag != tree::Tag::of::<State>() {
+        return None;
+    }
+
+    tree.state.downcast_mut::<State>().moved.take()
+}
+
+pub(crate) fn set_active(tree: &mut Tree, active: Option<usize>) {
+    if tree.tag == tree::Tag::of::<State>() {
+        tree.state.downcast_mut::<State>().active = active;
+    }
+}
+
+pub(crate) fn take_selection(tree: &mut Tree) -> Option<usize> {
+    if tree.tag != tree::Tag::of::<State>() {
+        return None;
+    }
+
+    tree.state.downcast_mut::<State>().selection.take()
+}
+
+pub(crate) fn take_close(tree: &mut Tree) -> Option<usize> {
+    if tree.tag != tree::Tag::of::<State>() {
+        return None;
+    }
+
+    tree.state.downcast_mut::<State>().close.take()
+}
+
+pub(crate) struct TabBar<'a, Message, Renderer> {
+    items: Vec<TabItem<'a, Message, Renderer>>,
+    mode: Mode,
+    direction: LayoutDirection,
+    reorderable: bool,
+}
+
+impl<'a, Message, Renderer> TabBar<'a, Message, Renderer> {
+    pub fn new(
+        items: Vec<TabItem<'a, Message, Renderer>>,
+        mode: Mode,
+        direction: LayoutDirection,
+    ) -> Self {
+        Self {
+            items,
+            mode,
+            direction,
+            reorderable: false,
+        }
+    }
+
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+
+        self
+    }
+
+    fn is_reversed(&self) -> bool {
+        !self.mode.is_vertical() && self.direction.is_rtl()
+    }
+
+    fn drop_slot(&self, layout: Layout<'_>, position: Point) -> usize {
+        let bounds = layout.bounds();
+        let count = self.items.len();
+        let cell = layout
+            .children()
+            .next()
+            .map(|cell| cell.bounds().size())
+            .unwrap_or(Size::ZERO);
+
+        let slot = if self.mode.is_vertical() {
+            (position.y - bounds.y) / cell.height.max(1.0)
+        } else {
+            (position.x - bounds.x) / cell.width.max(1.0)
+        };
+        let slot = (slot.round().max(0.0) as usize).min(count);
+
+        if self.is_reversed() {
+            count - slot
+        } else {
+            slot
+        }
+    }
+
+    fn drop_indicator(&self, layout: Layout<'_>, slot: usize) -> Rectangle {
+        let bounds = layout.bounds();
+        let count = self.items.len();
+        let cell = layout
+            .children()
+            .next()
+            .map(|cell| cell.bounds().size())
+            .unwrap_or(Size::ZERO);
+        let slot = if self.is_reversed() {
+            count - slot
+        } else {
+            slot
+        } as f32;
+
+        if self.mode.is_vertical() {
+            Rectangle {
+                y: bounds.y + slot * cell.height - DROP_INDICATOR / 2.0,
+                height: DROP_INDICATOR,
+                ..bounds
+            }
+        } else {
+            Rectangle {
+                x: bounds.x + slot * cell.width - DROP_INDICATOR / 2.0,
+                width: DROP_INDICATOR,
+                ..bounds
+            }
+        }
+    }
+
+    fn cell_at(&self, layout: Layout<'_>, position: Point) -> Option<usize> {
+        layout
+            .children()
+            .position(|cell| cell.bounds().contains(position))
+    }
+
+    fn over_close(&self, layout: Layout<'_>, index: usize, position: Point) -> bool {
+        self.items[index].close.is_some()
+            && layout
+                .child(index)
+                .child(2)
+                .bounds()
+                .expand(CLOSE_PADDING)
+                .contains(position)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Theme, Renderer> for TabBar<'a, Message, Renderer>
+where
+    Renderer: iced_core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        if self.mode.is_vertical() {
+            Size {
+                width: Length::Fixed(RAIL_WIDTH),
+                height: Length::Fill,
+            }
+        } else {
+            Size {
+                width: Length::Fill,
+                height: Length::Fixed(HEIGHT),
+            }
+        }
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.items
+            .iter()
+            .flat_map(|item| {
+                [
+                    Tree::new(&item.content),
+                    item.badge
+                        .as_ref()
+                        .map(Tree::new)
+                        .unwrap_or_else(Tree::empty),
+                    item.close
+                        .as_ref()
+                        .map(Tree::new)
+                        .unwrap_or_else(Tree::empty),
+                ]
+            })
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if tree.children.len() != self.items.len() * 3 {
+            tree.children = self.children();
+            return;
+        }
+
+        for (item, children) in self.items.iter().zip(tree.children.chunks_mut(3)) {
+            children[0].diff(&item.content);
+
+            match item.badge.as_ref() {
+                Some(badge) => children[1].diff(badge),
+                None => children[1] = Tree::empty(),
+            }
+
+            match item.close.as_ref() {
+                Some(close) => children[2].diff(close),
+                None => children[2] = Tree::empty(),
+            }
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Size { width, height } = self.size();
+        let size = limits
+            .width(width)
+            .height(height)
+            .resolve(width, height, Size::ZERO);
+
+        let count = self.items.len().max(1) as f32;
+        let vertical = self.mode.is_vertical();
+        let cell = if vertical {
+            Size::new(size.width, (size.height / count).min(HEIGHT))
+        } else {
+            Size::new(size.width / count, size.height)
+        };
+        let limits = layout::Limits::new(Size::ZERO, cell);
+        let state = tree.state.downcast_ref::<State>();
+        let slots: Vec<usize> = (0..self.items.len())
+            .map(|index| state.slot(index))
+            .collect();
+
+        let cells = self
+            .items
+            .iter_mut()
+            .zip(tree.children.chunks_mut(3))
+            .enumerate()
+            .map(|(index, (item, children))| {
+                let slot = slots[index] as f32;
+                let position = match (vertical, self.direction) {
+                    (true, _) => Point::new(0.0, slot * cell.height),
+                    (false, LayoutDirection::LeftToRight) => Point::new(slot * cell.width, 0.0),
+                    (false, LayoutDirection::RightToLeft) => {
+                        Point::new((count - 1.0 - slot) * cell.width, 0.0)
+                    }
+                };
+
+                let content =
+                    item.content
+                        .as_widget_mut()
+                        .layout(&mut children[0], renderer, &limits);
+                let content_size = content.size();
+                let content = content.move_to(Point::new(
+                    (cell.width - content_size.width) / 2.0,
+                    (cell.height - content_size.height) / 2.0,
+                ));
+
+                let badge = item
+                    .badge
+                    .as_mut()
+                    .map(|badge| {
+                        let node =
+                            badge
+                                .as_widget_mut()
+                                .layout(&mut children[1], renderer, &limits);
+                        let width = node.size().width;
+                        let x = content.bounds().x + content_size.width - BADGE_PADDING;
+
+                        node.move_to(Point::new(
+                            x.min(cell.width - width - BADGE_PADDING),
+                            (content.bounds().y - BADGE_PADDING).max(BADGE_PADDING),
+                        ))
+                    })
+                    .unwrap_or_else(|| layout::Node::new(Size::ZERO));
+
+                let close = item
+                    .close
+                    .as_mut()
+                    .map(|close| {
+                        let node =
+                            close
+                                .as_widget_mut()
+                                .layout(&mut children[2], renderer, &limits);
+                        let size = node.size();
+
+                        node.move_to(Point::new(
+                            cell.width - size.width - CLOSE_PADDING * 2.0,
+                            CLOSE_PADDING,
+                        ))
+                    })
+                    .unwrap_or_else(|| layout::Node::new(Size::ZERO));
+
+                layout::Node::with_children(cell, vec![content, badge, close]).move_to(position)
+            })
+            .collect();
+
+        layout::Node::with_children(size, cells)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let position = cursor.position_over(layout.bounds());
+        let cell = position
+            .and_then(|position| self.cell_at(layout, position))
+            .filter(|index| self.items[*index].enabled);
+        let target = cell
+            .zip(position)
+            .map(|(index, position)| (index, self.over_close(layout, index, position)));
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.drag.is_some() =>
+            {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+                let Some(drag) = state.drag.as_mut() else {
+                    return;
+                };
+
+                let distance = if self.mode.is_vertical() {
+                    (position.y - drag.origin.y).abs()
+                } else {
+                    (position.x - drag.origin.x).abs()
+                };
+
+                if drag.target.is_some() || distance > DRAG_THRESHOLD {
+                    let slot = Some(self.drop_slot(layout, position));
+
+                    if drag.target != slot {
+                        drag.target = slot;
+                        shell.request_redraw();
+                    }
+
+                    state.hovered = None;
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Mouse(mouse::Event::CursorLeft)
+                if state.hovered != target =>
+            {
+                state.hovered = target;
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if target.is_some() =>
+            {
+                state.pressed = target;
+                state.drag = target
+                    .zip(position)
+                    .filter(|((_, close), _)| self.reorderable && !close)
+                    .map(|((item, _), origin)| Drag {
+                        item,
+                        origin,
+                        target: None,
+                    });
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(Drag {
+                    item,
+                    target: Some(slot),
+                    ..
+                }) = state.drag.take()
+                {
+                    let current = state.slot(item);
+
+                    if slot != current && slot != current + 1 {
+                        state.moved = Some((item, slot));
+                    }
+
+                    state.pressed = None;
+                    state.hovered = target;
+                    shell.capture_event();
+                    shell.request_redraw();
+                } else if let Some(pressed) = state.pressed.take()
+                    && target == Some(pressed)
+                {
+                    match pressed {
+                        (index, true) => state.close = Some(index),
+                        (index, false) => state.selection = Some(index),
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+
+                if state.drag.take().is_some() {
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some_and(|drag| drag.target.is_some()) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let cell = cursor
+            .position_over(layout.bounds())
+            .and_then(|position| self.cell_at(layout, position));
+
+        match cell.map(|index| self.items[index].enabled) {
+            Some(true) => mouse::Interaction::Pointer,
+            Some(false) => mouse::Interaction::NotAllowed,
+            None => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let palette = theme.extended_palette();
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                ..Default::default()
+            },
+            palette.background.weak.color,
+        );
+
+        for (index, ((item, children), cell)) in self
+            .items
+            .iter()
+            .zip(tree.children.chunks(3))
+            .zip(layout.children())
+            .enumerate()
+        {
+            let active = state.active == Some(index);
+            let cell_bounds = cell.bounds();
+            let mut cell_layout = cell.children();
+            let content_layout = cell_layout.next().unwrap();
+            let badge_layout = cell_layout.next().unwrap();
+            let close_layout = cell_layout.next().unwrap();
+
+            if state.hovered.is_some_and(|(hovered, _)| hovered == index) && !active {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: cell_bounds,
+                        ..Default::default()
+                    },
+                    palette.background.strong.color.scale_alpha(0.3),
+                );
+            }
+
+            if active {
+                let indicator = match self.mode {
+                    Mode::Top => Rectangle {
+                        y: cell_bounds.y + cell_bounds.height - INDICATOR,
+                        height: INDICATOR,
+                        ..cell_bounds
+                    },
+                    Mode::Bottom => Rectangle {
+                        height: INDICATOR,
+                        ..cell_bounds
+                    },
+                    Mode::Left => Rectangle {
+                        x: cell_bounds.x + cell_bounds.width - INDICATOR,
+                        width: INDICATOR,
+                        ..cell_bounds
+                    },
+                    Mode::Right => Rectangle {
+                        width: INDICATOR,
+                        ..cell_bounds
+                    },
+                };
+
+                renderer.fill_quad(
+                    Quad {
+                        bounds: indicator,
+                        ..Default::default()
+                    },
+                    palette.primary.base.color,
+                );
+            }
+
+            let dragged = state
+                .drag
+                .is_some_and(|drag| drag.item == index && drag.target.is_some());
+
+            let style = renderer::Style {
+                text_color: if dragged {
+                    palette.background.weak.text.scale_alpha(0.3)
+                } else if active {
+                    palette.primary.base.color
+                } else if item.enabled {
+                    palette.background.weak.text.scale_alpha(0.7)
+                } else {
+                    palette.background.weak.text.scale_alpha(0.3)
+                },
+            };
+
+            item.content.as_widget().draw(
+                &children[0],
+                renderer,
+                theme,
+                &style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+
+            if let Some(badge) = item.badge.as_ref() {
+                let badge_bounds = badge_layout.bounds();
+
+                renderer.with_layer(bounds, |renderer| {
+                    renderer.fill_quad(
+                        Quad {
+                            bounds: Rectangle {
+                                x: badge_bounds.x - BADGE_PADDING,
+                                width: badge_bounds.width + BADGE_PADDING * 2.0,
+                                ..badge_bounds
+                            },
+                            border: Border::default().rounded(badge_bounds.height / 2.0),
+                            ..Default::default()
+                        },
+                        palette.danger.base.color,
+                    );
+
+                    badge.as_widget().draw(
+                        &children[1],
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: palette.danger.base.text,
+                        },
+                        badge_layout,
+                        cursor,
+                        viewport,
+                    );
+                });
+            }
+
+            if let Some(close) = item.close.as_ref() {
+                let close_bounds = close_layout.bounds().expand(CLOSE_PADDING);
+
+                if state.hovered == Some((index, true)) {
+                    renderer.fill_quad(
+                        Quad {
+                            bounds: close_bounds,
+                            border: Border::default().rounded(close_bounds.height / 2.0),
+                            ..Default::default()
+                        },
+                        palette.background.strong.color,
+                    );
+                }
+
+                close.as_widget().draw(
+                    &children[2],
+                    renderer,
+                    theme,
+                    &style,
+                    close_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+
+        if let Some(slot) = state.drag.and_then(|drag| drag.target) {
+            renderer.fill_quad(
+                Quad {
+                    bounds: self.drop_indicator(layout, slot),
+                    ..Default::default()
+                },
+                palette.primary.base.color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<TabBar<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced_core::Renderer,
+{
+    fn from(tab_bar: TabBar<'a, Message, Renderer>) -> Self {
+        Self::new(tab_bar)
+    }
+}
@@ -1,27 +1,167 @@
+use std::collections::HashSet;
 use std::hash::Hash;
 use std::mem::Discriminant;
+use std::ops::Div;
 
 use iced_core::{
     Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle, Shell, Size, Theme,
-    Vector, Widget,
+    Vector, Widget, keyboard,
     layout::{self, Node},
-    mouse, overlay, renderer,
+    mouse, overlay,
+    renderer::{self, Quad},
+    text,
+    time::Instant,
+    touch,
     widget::{Id, Operation, Tree, tree},
     window,
 };
 use indexmap::IndexMap;
 
+use crate::animation::{Animation, Animator, Easing, Frame, Spring, SpringFrame, easing};
+use crate::direction::{self, LayoutDirection};
+use crate::widgets::operations;
+use crate::widgets::tab_bar::{self, TabBar, TabItem};
 use crate::widgets::{NavigatorElement, NavigatorElementSource, NavigatorState};
 
+pub use crate::widgets::tab_bar::TabOptions;
+
 type TabsBuilderFn<'a, Key, Message, Theme, Renderer> =
-    dyn for<'b> Fn(PageParams<Key>, &Vec<Key>) -> Element<'a, Message, Theme, Renderer> + 'a;
+    dyn for<'b> Fn(PageParams<Key>, &Vec<Tab<Key>>) -> Element<'a, Message, Theme, Renderer> + 'a;
 
 type OnNavigationEnd<'a, Key, Message> = dyn Fn(Option<Key>, Key) -> Message + 'a;
 
+type OnTabReselect<'a, Key, Message> = dyn Fn(Key) -> Message + 'a;
+
+type OnNavigationRejected<'a, Key, Message> = dyn Fn(Key) -> Message + 'a;
+
+type OnTabClose<'a, Key, Message> = dyn Fn(Key) -> Message + 'a;
+
+type OnTabsReordered<'a, Key, Message> = dyn Fn(Vec<Key>) -> Message + 'a;
+
+type KeyBinding<'a> = dyn Fn(&keyboard::Key, keyboard::Modifiers) -> Option<TabShortcut> + 'a;
+
+type PageDraw<'a, 'b, Message, Renderer> = (
+    &'b Element<'a, Message, Theme, Renderer>,
+    &'b Tree,
+    Layout<'b>,
+);
+
 #[derive(Debug, Clone, Copy)]
 pub enum Mode {
     Top,
     Bottom,
+    Left,
+    Right,
+}
+
+impl Mode {
+    pub(crate) fn is_vertical(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    fn axis(self) -> layout::flex::Axis {
+        if self.is_vertical() {
+            layout::flex::Axis::Horizontal
+        } else {
+            layout::flex::Axis::Vertical
+        }
+    }
+
+    fn leads(self) -> bool {
+        matches!(self, Self::Top | Self::Left)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TabTransition {
+    #[default]
+    None,
+    Fade,
+    Slide,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReselectBehavior {
+    #[default]
+    None,
+    ScrollToTop,
+    #[cfg(feature = "stack")]
+    PopToRoot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabShortcut {
+    Next,
+    Previous,
+    Jump(usize),
+}
+
+pub fn default_shortcut(
+    key: &keyboard::Key,
+    modifiers: keyboard::Modifiers,
+) -> Option<TabShortcut> {
+    match key.as_ref() {
+        keyboard::Key::Named(keyboard::key::Named::Tab) if modifiers.control() => {
+            Some(if modifiers.shift() {
+                TabShortcut::Previous
+            } else {
+                TabShortcut::Next
+            })
+        }
+        keyboard::Key::Character(digit) if modifiers.command() => digit
+            .parse::<usize>()
+            .ok()
+            .filter(|digit| (1..=9).contains(digit))
+            .map(|digit| TabShortcut::Jump(digit - 1)),
+        _ => None,
+    }
+}
+
+const DRAG_THRESHOLD: f32 = 10.0;
+const FLING_PROJECTION: f32 = 0.2;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Drag {
+    origin: Point,
+    offset: f32,
+    velocity: f32,
+    time: Instant,
+    dragging: bool,
+}
+
+impl Drag {
+    fn new(origin: Point) -> Self {
+        Self {
+            origin,
+            offset: 0.0,
+            velocity: 0.0,
+            time: Instant::now(),
+            dragging: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Slot<Key> {
+    Page(Discriminant<Key>),
+    Document(Key),
+}
+
+fn find_slot<Key: Eq + Clone>(slots: &[Slot<Key>], key: &Key) -> Slot<Key> {
+    let document = Slot::Document(key.clone());
+
+    if slots.contains(&document) {
+        document
+    } else {
+        Slot::Page(std::mem::discriminant(key))
+    }
+}
+
+fn order_of<Key: Eq>(order: &[Slot<Key>], slot: &Slot<Key>) -> usize {
+    order
+        .iter()
+        .position(|disc| disc == slot)
+        .unwrap_or(usize::MAX)
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +169,155 @@ pub struct State<Key: Eq + Hash> {
     pub(crate) history: Vec<Key>,
     pub(crate) previous_page: Option<Key>,
     pub(crate) pending_update: bool,
+    pub(crate) outgoing: Option<Key>,
+    pub(crate) frame: Option<Animator>,
+    pub(crate) transition: TabTransition,
+    pub(crate) transition_duration: f32,
+    pub(crate) easing: Easing,
+    pub(crate) spring: Option<Spring>,
+    pub(crate) drag: Option<Drag>,
+    pub(crate) start: f32,
+    pub(crate) mounted: HashSet<Slot<Key>>,
+    pub(crate) reselected: Option<Key>,
+    pub(crate) slots: Vec<Slot<Key>>,
+    pub(crate) disabled: HashSet<Slot<Key>>,
+    pub(crate) rejected: Option<Key>,
+    pub(crate) closing: Option<Key>,
+    pub(crate) order: Vec<Slot<Key>>,
+    pub(crate) reordered: bool,
+}
+
+impl<Key: 'static + Eq + Hash + Clone> State<Key> {
+    fn slot(&self, key: &Key) -> Slot<Key> {
+        find_slot(&self.slots, key)
+    }
+
+    fn new_frame(&self) -> Animator {
+        match self.spring {
+            Some(spring) => SpringFrame::new(spring).into(),
+            None => Frame::new()
+                .duration(self.transition_duration)
+                .map(self.easing)
+                .into(),
+        }
+    }
+
+    fn begin_transition(&mut self, previous: Option<Key>) {
+        let Some(previous) = previous else {
+            return;
+        };
+
+        let unchanged = self
+            .history
+            .last()
+            .is_some_and(|current| self.slot(current) == self.slot(&previous));
+
+        if self.transition == TabTransition::None || unchanged {
+            return;
+        }
+
+        self.outgoing = Some(previous);
+        self.frame = Some(self.new_frame());
+        self.start = 0.0;
+    }
+
+    fn begin_slide(&mut self, outgoing: Key, start: f32) {
+        self.outgoing = Some(outgoing);
+        self.frame = Some(self.new_frame());
+        self.start = start.clamp(0.0, 1.0);
+    }
+
+    pub(crate) fn close_tab(&mut self, key: Key) {
+        if let Slot::Document(key) = self.slot(&key) {
+            self.closing = Some(key);
+        }
+    }
+
+    pub(crate) fn move_tab(&mut self, key: Key, index: usize) {
+        let slot = self.slot(&key);
+        let mut order = self.slots.clone();
+
+        let Some(position) = order.iter().position(|disc| *disc == slot) else {
+            return;
+        };
+
+        order.remove(position);
+        order.insert(index.min(order.len()), slot);
+
+        if order != self.slots {
+            self.order = order;
+            self.reordered = true;
+        }
+    }
+
+    fn reconcile(
+        &mut self,
+        children: &mut Vec<Tree>,
+        slots: Vec<Slot<Key>>,
+        pages: &[Key],
+        home_page: &Key,
+    ) {
+        if slots == self.slots && children.len() == slots.len() + 1 {
+            return;
+        }
+
+        let tabs = children.pop().unwrap_or_else(Tree::empty);
+        let mut previous: Vec<_> = std::mem::take(&mut self.slots)
+            .into_iter()
+            .zip(children.drain(..))
+            .collect();
+
+        children.extend(slots.iter().map(|disc| {
+            previous
+                .iter()
+                .position(|(slot, _)| slot == disc)
+                .map(|index| previous.swap_remove(index).1)
+                .unwrap_or_else(Tree::empty)
+        }));
+        children.push(tabs);
+
+        let present = |key: &Key| slots.contains(&find_slot(&slots, key));
+        let current = self.history.last().cloned();
+
+        self.history.retain(present);
+        self.history
+            .dedup_by(|a, b| find_slot(&slots, a) == find_slot(&slots, b));
+        self.mounted.retain(|disc| slots.contains(disc));
+        self.previous_page = self.previous_page.take().filter(present);
+        self.reselected = self.reselected.take().filter(present);
+
+        if let Some(current) = current.filter(|current| !present(current)) {
+            self.previous_page = Some(current);
+            self.drag = None;
+            self.pending_update = true;
+        }
+
+        if self
+            .outgoing
+            .as_ref()
+            .is_some_and(|outgoing| !present(outgoing))
+        {
+            self.outgoing = None;
+            self.frame = None;
+        }
+
+        if self.history.is_empty() {
+            self.history.extend(
+                std::iter::once(home_page)
+                    .chain(pages)
+                    .find(|key| present(key))
+                    .cloned(),
+            );
+        }
+
+        self.slots = slots;
+    }
+
+    fn progress(&self) -> Option<f32> {
+        let frame = self.frame.as_ref()?;
+
+        Some(self.start + (1.0 - self.start) * frame.get_value().div(100.0).clamp(0.0, 1.0))
+    }
 }
 
 impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
@@ -47,8 +336,28 @@ impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
     }
 
     fn navigate(&mut self, page: Key) {
+        if !self.slots.contains(&self.slot(&page)) {
+            return;
+        }
+
+        let previous = self.history.last().cloned();
+
+        if previous
+            .as_ref()
+            .is_some_and(|current| self.slot(current) == self.slot(&page))
+        {
+            self.reselected = Some(page);
+            return;
+        }
+
+        if self.disabled.contains(&self.slot(&page)) {
+            self.rejected = Some(page);
+            return;
+        }
+
         self.history.push(page);
         self.previous_page = None;
+        self.begin_transition(previous);
     }
 
     fn go_back(&mut self) {
@@ -57,6 +366,7 @@ impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
         }
 
         self.previous_page = self.history.pop();
+        self.begin_transition(self.previous_page.clone());
     }
 
     fn clear_history(&mut self) {
@@ -75,6 +385,14 @@ impl<Key: 'static + Eq + Hash + Clone> NavigatorState for State<Key> {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tab<Key> {
+    pub key: Key,
+    pub enabled: bool,
+    pub visible: bool,
+    pub closable: bool,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct PageParams<Key> {
     pub current_page: Key,
@@ -93,11 +411,27 @@ where
     pages: Vec<Key>,
     tabs_builder: Option<Box<TabsBuilderFn<'a, Key, Message, Theme, Renderer>>>,
     tabs_cache: NavigatorElement<'a, PageParams<Key>, Message, Theme, Renderer>,
-    children: IndexMap<
-        Discriminant<Key>,
-        NavigatorElement<'a, PageParams<Key>, Message, Theme, Renderer>,
-    >,
+    tab_options: IndexMap<Key, TabItem<'a, Message, Renderer>>,
+    tab_keys: Vec<Key>,
+    children: IndexMap<Slot<Key>, NavigatorElement<'a, PageParams<Key>, Message, Theme, Renderer>>,
     on_navigation_end: Option<Box<OnNavigationEnd<'a, Key, Message>>>,
+    on_tab_reselect: Option<Box<OnTabReselect<'a, Key, Message>>>,
+    on_navigation_rejected: Option<Box<OnNavigationRejected<'a, Key, Message>>>,
+    on_tab_close: Option<Box<OnTabClose<'a, Key, Message>>>,
+    on_tabs_reordered: Option<Box<OnTabsReordered<'a, Key, Message>>>,
+    reorderable: bool,
+    disabled: HashSet<Slot<Key>>,
+    hidden: HashSet<Slot<Key>>,
+    key_binding: Option<Box<KeyBinding<'a>>>,
+    reselect_behavior: ReselectBehavior,
+    transition: TabTransition,
+    transition_duration: f32,
+    easing: Easing,
+    spring: Option<Spring>,
+    direction: Option<LayoutDirection>,
+    pager: bool,
+    lazy: bool,
+    unmount_on_blur: HashSet<Slot<Key>>,
 }
 
 impl<'a, Key, Message, Renderer> TabsNavigator<'a, Key, Message, Renderer>
@@ -113,8 +447,27 @@ where
             tabs_builder: None,
             children: IndexMap::new(),
             tabs_cache: NavigatorElement::empty(),
+            tab_options: IndexMap::new(),
+            tab_keys: Vec::new(),
             on_navigation_end: None,
+            on_tab_reselect: None,
+            on_navigation_rejected: None,
+            on_tab_close: None,
+            on_tabs_reordered: None,
+            reorderable: false,
+            disabled: HashSet::new(),
+            hidden: HashSet::new(),
+            key_binding: None,
+            reselect_behavior: ReselectBehavior::None,
             pages: Vec::new(),
+            transition: TabTransition::None,
+            transition_duration: 0.2,
+            easing: easing::linear,
+            spring: None,
+            direction: None,
+            pager: false,
+            lazy: false,
+            unmount_on_blur: HashSet::new(),
             home_page,
         }
     }
@@ -139,7 +492,7 @@ where
         key: Key,
         page: impl Into<Element<'a, Message, Theme, Renderer>>,
     ) -> Self {
-        let disc = std::mem::discriminant(&key);
+        let disc = Slot::Page(std::mem::discriminant(&key));
 
         self.children
             .insert(disc, NavigatorElementSource::Direct(page.into()).into());
@@ -153,7 +506,7 @@ where
         key: Key,
         fun: impl Fn(PageParams<Key>) -> Element<'a, Message, Theme, Renderer> + 'a,
     ) -> Self {
-        let disc = std::mem::discriminant(&key);
+        let disc = Slot::Page(std::mem::discriminant(&key));
         let item = NavigatorElementSource::Closure(Box::new(fun));
 
         self.children.insert(disc, item.into());
@@ -162,15 +515,53 @@ where
         self
     }
 
+    pub fn insert_document(
+        mut self,
+        key: Key,
+        page: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.children.insert(
+            Slot::Document(key.clone()),
+            NavigatorElementSource::Direct(page.into()).into(),
+        );
+
+        self.pages.push(key);
+        self
+    }
+
+    pub fn insert_document_with(
+        mut self,
+        key: Key,
+        fun: impl Fn(PageParams<Key>) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        let item = NavigatorElementSource::Closure(Box::new(fun));
+
+        self.children
+            .insert(Slot::Document(key.clone()), item.into());
+        self.pages.push(key);
+
+        self
+    }
+
     pub fn tabs_widget(
         mut self,
-        fun: impl Fn(PageParams<Key>, &Vec<Key>) -> Element<'a, Message, Theme, Renderer> + 'a,
+        fun: impl Fn(PageParams<Key>, &Vec<Tab<Key>>) -> Element<'a, Message, Theme, Renderer> + 'a,
     ) -> Self {
         self.tabs_builder = Some(Box::new(fun));
 
         self
     }
 
+    pub fn tab_options(mut self, key: Key, options: TabOptions<'a, Message, Renderer>) -> Self
+    where
+        Message: 'a,
+        Renderer: text::Renderer + 'a,
+    {
+        self.tab_options.insert(key, options.into_item());
+
+        self
+    }
+
     pub fn mode(mut self, mode: Mode) -> Self {
         self.mode = mode;
 
@@ -185,14 +576,509 @@ where
 
         self
     }
+
+    pub fn on_tab_reselect(mut self, on_tab_reselect: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_tab_reselect = Some(Box::new(on_tab_reselect));
+
+        self
+    }
+
+    pub fn on_navigation_rejected(
+        mut self,
+        on_navigation_rejected: impl Fn(Key) -> Message + 'a,
+    ) -> Self {
+        self.on_navigation_rejected = Some(Box::new(on_navigation_rejected));
+
+        self
+    }
+
+    pub fn on_tab_close(mut self, on_tab_close: impl Fn(Key) -> Message + 'a) -> Self {
+        self.on_tab_close = Some(Box::new(on_tab_close));
+
+        self
+    }
+
+    pub fn on_tabs_reordered(
+        mut self,
+        on_tabs_reordered: impl Fn(Vec<Key>) -> Message + 'a,
+    ) -> Self {
+        self.on_tabs_reordered = Some(Box::new(on_tabs_reordered));
+
+        self
+    }
+
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+
+        self
+    }
+
+    pub fn enabled(mut self, key: Key, enabled: bool) -> Self {
+        let disc = self.slot(&key);
+
+        if enabled {
+            self.disabled.remove(&disc);
+        } else {
+            self.disabled.insert(disc);
+        }
+
+        self
+    }
+
+    pub fn visible(mut self, key: Key, visible: bool) -> Self {
+        let disc = self.slot(&key);
+
+        if visible {
+            self.hidden.remove(&disc);
+        } else {
+            self.hidden.insert(disc);
+        }
+
+        self
+    }
+
+    pub fn keyboard_shortcuts(mut self, enabled: bool) -> Self {
+        self.key_binding = if enabled {
+            Some(Box::new(default_shortcut))
+        } else {
+            None
+        };
+
+        self
+    }
+
+    pub fn key_binding(
+        mut self,
+        key_binding: impl Fn(&keyboard::Key, keyboard::Modifiers) -> Option<TabShortcut> + 'a,
+    ) -> Self {
+        self.key_binding = Some(Box::new(key_binding));
+
+        self
+    }
+
+    pub fn reselect_behavior(mut self, behavior: ReselectBehavior) -> Self {
+        self.reselect_behavior = behavior;
+
+        self
+    }
+
+    pub fn transition(mut self, transition: TabTransition) -> Self {
+        self.transition = transition;
+
+        self
+    }
+
+    pub fn transition_duration(mut self, duration: f32) -> Self {
+        self.transition_duration = duration;
+
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+
+        self
+    }
+
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.spring = Some(spring);
+
+        self
+    }
+
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+
+        self
+    }
+
+    pub fn unmount_on_blur(mut self, key: Key) -> Self {
+        self.unmount_on_blur.insert(self.slot(&key));
+
+        self
+    }
+
+    pub fn pager(mut self, pager: bool) -> Self {
+        self.pager = pager;
+
+        self
+    }
+
+    pub fn direction(mut self, direction: LayoutDirection) -> Self {
+        self.direction = Some(direction);
+
+        self
+    }
+
+    fn slot(&self, key: &Key) -> Slot<Key> {
+        let document = Slot::Document(key.clone());
+
+        if self.children.contains_key(&document) {
+            document
+        } else {
+            Slot::Page(std::mem::discriminant(key))
+        }
+    }
+
+    fn get_direction(&self) -> LayoutDirection {
+        self.direction.unwrap_or_else(direction::layout_direction)
+    }
+
+    fn mount_tab_bar(&mut self)
+    where
+        Message: 'a,
+        Renderer: iced_core::Renderer + 'a,
+    {
+        if self.tabs_builder.is_some() || self.tab_options.is_empty() {
+            return;
+        }
+
+        let mut options: IndexMap<_, _> = std::mem::take(&mut self.tab_options)
+            .into_iter()
+            .map(|(key, item)| (self.slot(&key), item))
+            .collect();
+        let mut items = Vec::with_capacity(options.len());
+
+        for tab in self.tabs().into_iter().filter(|tab| tab.visible) {
+            if let Some(item) = options.shift_remove(&self.slot(&tab.key)) {
+                self.tab_keys.push(tab.key);
+                items.push(item.enabled(tab.enabled).closable(tab.closable));
+            }
+        }
+
+        let tab_bar =
+            TabBar::new(items, self.mode, self.get_direction()).reorderable(self.reorderable);
+
+        self.tabs_cache = NavigatorElementSource::Direct(tab_bar.into()).into();
+    }
+
+    fn ordered_slots(&self, state: &State<Key>) -> Vec<Slot<Key>> {
+        let mut slots: Vec<_> = self.children.keys().cloned().collect();
+
+        slots.sort_by_cached_key(|slot| order_of(&state.order, slot));
+
+        slots
+    }
+
+    fn ordered_pages(&self, state: &State<Key>) -> Vec<Key> {
+        let mut pages = self.pages.clone();
+
+        pages.sort_by_cached_key(|key| order_of(&state.order, &self.slot(key)));
+
+        pages
+    }
+
+    fn apply_order(&mut self, state: &State<Key>) {
+        if state.order.is_empty() {
+            return;
+        }
+
+        self.pages = self.ordered_pages(state);
+        self.children
+            .sort_by_cached_key(|slot, _| order_of(&state.order, slot));
+    }
+
+    fn tab_bar_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.tab_keys.len()).collect();
+
+        order.sort_by_cached_key(|index| self.page_position(&self.tab_keys[*index]));
+
+        order
+    }
+
+    fn move_tab(&self, item: usize, slot: usize) -> Option<Vec<Key>> {
+        let position = self.page_position(self.tab_keys.get(item)?)?;
+        let displayed: Vec<&Key> = self
+            .pages
+            .iter()
+            .filter(|key| self.tab_index(key).is_some())
+            .collect();
+
+        let mut pages = self.pages.clone();
+        let dragged = pages.remove(position);
+
+        let index = match displayed.get(slot) {
+            Some(anchor) => pages.iter().position(|key| key == *anchor)?,
+            None => {
+                pages
+                    .iter()
+                    .position(|key| Some(&key) == displayed.last())?
+                    + 1
+            }
+        };
+
+        pages.insert(index, dragged);
+
+        Some(pages).filter(|pages| *pages != self.pages)
+    }
+
+    fn tabs(&self) -> Vec<Tab<Key>> {
+        self.pages
+            .iter()
+            .map(|key| {
+                let disc = self.slot(key);
+
+                Tab {
+                    key: key.clone(),
+                    enabled: !self.disabled.contains(&disc),
+                    visible: !self.hidden.contains(&disc),
+                    closable: matches!(disc, Slot::Document(_)),
+                }
+            })
+            .collect()
+    }
+
+    fn shortcut_target(&self, state: &State<Key>, shortcut: TabShortcut) -> Option<Key> {
+        let reachable: Vec<Key> = self
+            .tabs()
+            .into_iter()
+            .filter(|tab| tab.enabled && tab.visible)
+            .map(|tab| tab.key)
+            .collect();
+
+        let current = self.slot(state.history.last()?);
+        let position = reachable.iter().position(|key| self.slot(key) == current);
+        let len = reachable.len();
+
+        let index = match (shortcut, position) {
+            (TabShortcut::Jump(index), _) => index,
+            (TabShortcut::Next, Some(position)) => (position + 1) % len,
+            (TabShortcut::Previous, Some(position)) => (position + len - 1) % len,
+            (TabShortcut::Next, None) => 0,
+            (TabShortcut::Previous, None) => len.checked_sub(1)?,
+        };
+
+        reachable
+            .get(index)
+            .filter(|key| self.slot(*key) != current)
+            .cloned()
+    }
+
+    fn tab_index(&self, key: &Key) -> Option<usize> {
+        let disc = self.slot(key);
+
+        self.tab_keys.iter().position(|tab| self.slot(tab) == disc)
+    }
+
+    fn is_mounted(&self, state: &State<Key>, key: &Key) -> bool {
+        !self.lazy || state.mounted.contains(&self.slot(key))
+    }
+
+    fn mounted_neighbors(&self, state: &State<Key>, key: &Key) -> [Option<Key>; 2] {
+        self.neighbors(key)
+            .map(|page| page.filter(|page| self.is_mounted(state, page)))
+    }
+
+    fn neighbors(&self, key: &Key) -> [Option<Key>; 2] {
+        let Some(position) = self.page_position(key) else {
+            return [None, None];
+        };
+
+        let reachable = |key: &&Key| {
+            let disc = self.slot(*key);
+
+            !self.disabled.contains(&disc) && !self.hidden.contains(&disc)
+        };
+
+        [
+            self.pages[..position].iter().rev().find(reachable).cloned(),
+            self.pages[position + 1..].iter().find(reachable).cloned(),
+        ]
+    }
+
+    fn layout_page(
+        &mut self,
+        key: &Key,
+        can_go_back: bool,
+        bounds: Rectangle,
+        children: &mut [Tree],
+        renderer: &Renderer,
+    ) -> Option<Node>
+    where
+        Renderer: iced_core::Renderer,
+    {
+        let disc = self.slot(key);
+        let index = self.children.get_index_of(&disc)?;
+        let page = self.children.get_mut(&disc)?;
+
+        if page.is_empty() {
+            page.update_cache(PageParams {
+                current_page: key.clone(),
+                can_go_back,
+            });
+        }
+
+        let mut element = page.take_element().unwrap();
+
+        children[index].diff(&element);
+
+        let node = element.as_widget_mut().layout(
+            &mut children[index],
+            renderer,
+            &layout::Limits::new(Size::ZERO, bounds.size()),
+        );
+
+        page.return_element(element);
+
+        Some(node.move_to(bounds.position()))
+    }
+
+    fn update_drag(
+        &self,
+        state: &mut State<Key>,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool
+    where
+        Key: 'static,
+    {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.drag = Some(Drag::new(position));
+                }
+
+                false
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let [previous, next] = self.mounted_neighbors(state, state.history.last().unwrap());
+                let direction = self.get_direction();
+
+                let Some(drag) = state.drag.as_mut() else {
+                    return false;
+                };
+
+                let delta = *position - drag.origin;
+
+                if !drag.dragging {
+                    if delta.x.abs() < DRAG_THRESHOLD || delta.x.abs() < delta.y.abs() {
+                        return false;
+                    }
+
+                    drag.dragging = true;
+                    state.frame = None;
+                    state.outgoing = None;
+                    shell.invalidate_layout();
+                }
+
+                let toward_next = direction.mirror(delta.x) < 0.0;
+                let offset = match (toward_next, &previous, &next) {
+                    (true, _, Some(_)) | (false, Some(_), _) => {
+                        delta.x.clamp(-bounds.width, bounds.width)
+                    }
+                    _ => 0.0,
+                };
+
+                let now = Instant::now();
+                let elapsed = (now - drag.time).as_secs_f32();
+
+                if elapsed > 0.0 {
+                    drag.velocity = (offset - drag.offset) / elapsed;
+                }
+
+                drag.offset = offset;
+                drag.time = now;
+
+                shell.request_redraw();
+                shell.capture_event();
+                true
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                let Some(drag) = state.drag.take().filter(|drag| drag.dragging) else {
+                    return false;
+                };
+
+                let [previous, next] = self.mounted_neighbors(state, state.history.last().unwrap());
+                let toward_next = self.get_direction().mirror(drag.offset) < 0.0;
+                let fraction = (drag.offset / bounds.width).abs();
+                let projected = drag.offset + drag.velocity * FLING_PROJECTION;
+
+                let target = if toward_next { next } else { previous };
+                let settles = projected.abs() > bounds.width / 2.0
+                    && projected.signum() == drag.offset.signum();
+
+                match target {
+                    Some(target) if settles => {
+                        let current = state.history.last().cloned().unwrap();
+
+                        state.navigate(target);
+                        state.request_update();
+                        state.begin_slide(current, fraction);
+                    }
+                    Some(target) if fraction > 0.0 => {
+                        state.begin_slide(target, 1.0 - fraction);
+                    }
+                    _ => {}
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn apply_reselect(
+        &mut self,
+        key: &Key,
+        children: &mut [Tree],
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) where
+        Renderer: iced_core::Renderer,
+    {
+        let disc = self.slot(key);
+
+        let (Some(index), Some(page)) = (
+            self.children.get_index_of(&disc),
+            self.children
+                .get_mut(&disc)
+                .and_then(|page| page.get_element_mut()),
+        ) else {
+            return;
+        };
+
+        let page = page.as_widget_mut();
+
+        match self.reselect_behavior {
+            ReselectBehavior::None => {}
+            ReselectBehavior::ScrollToTop => page.operate(
+                &mut children[index],
+                layout,
+                renderer,
+                &mut operations::scroll_to_top(),
+            ),
+            #[cfg(feature = "stack")]
+            ReselectBehavior::PopToRoot => page.operate(
+                &mut children[index],
+                layout,
+                renderer,
+                &mut operations::request_root(),
+            ),
+        }
+    }
+
+    fn page_position(&self, key: &Key) -> Option<usize> {
+        let disc = self.slot(key);
+
+        self.pages.iter().position(|page| self.slot(page) == disc)
+    }
 }
 
 impl<'a, Key, Message, Renderer> Widget<Message, Theme, Renderer>
     for TabsNavigator<'a, Key, Message, Renderer>
 where
     Key: Eq + Hash + Clone + 'static,
-    Message: Clone,
-    Renderer: iced_core::Renderer,
+    Message: 'a + Clone,
+    Renderer: 'a + iced_core::Renderer,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State<Key>>()
@@ -210,6 +1096,22 @@ where
             pending_update: false,
             previous_page: None,
             history: vec![self.home_page.clone()],
+            outgoing: None,
+            frame: None,
+            transition: self.transition,
+            transition_duration: self.transition_duration,
+            easing: self.easing,
+            spring: self.spring,
+            drag: None,
+            start: 0.0,
+            mounted: HashSet::new(),
+            reselected: None,
+            slots: self.children.keys().cloned().collect(),
+            disabled: self.disabled.clone(),
+            rejected: None,
+            closing: None,
+            order: Vec::new(),
+            reordered: false,
         })
     }
 
@@ -231,7 +1133,7 @@ where
         let state = tree.state.downcast_mut::<State<Key>>();
         let children_len = tree.children.len();
         let key = state.history.last().unwrap();
-        let disc = std::mem::discriminant(key);
+        let disc = self.slot(key);
         let page_index = self.children.get_index_of(&disc).unwrap();
         let children_layout: Vec<_> = layout.children().collect();
 
@@ -261,16 +1163,18 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
-        if tree.children.len() > self.children.len() + 1
-            && let Some(item) = tree.children.pop()
-        {
-            tree.children.truncate(self.children.len());
-            tree.children.push(item);
-        }
+        let state = tree.state.downcast_mut::<State<Key>>();
 
-        while tree.children.len() < self.children.len() + 1 {
-            tree.children.insert(tree.children.len() - 2, Tree::empty());
-        }
+        let slots = self.ordered_slots(state);
+        let pages = self.ordered_pages(state);
+
+        state.reconcile(&mut tree.children, slots, &pages, &self.home_page);
+
+        state.disabled.clone_from(&self.disabled);
+        state.transition = self.transition;
+        state.transition_duration = self.transition_duration;
+        state.easing = self.easing;
+        state.spring = self.spring;
     }
 
     fn layout(
@@ -281,11 +1185,18 @@ where
     ) -> layout::Node {
         let limits = limits.width(self.width).height(self.height);
 
+        self.mount_tab_bar();
+
         let state = tree.state.downcast_mut::<State<Key>>();
 
+        self.apply_order(state);
+        let slots = self.ordered_slots(state);
+
+        state.reconcile(&mut tree.children, slots, &self.pages, &self.home_page);
+
         let children_len = tree.children.len();
         let key = state.history.last().unwrap();
-        let disc = std::mem::discriminant(key);
+        let disc = self.slot(key);
         let page_index = self.children.get_index_of(&disc).unwrap();
         let children = &mut tree.children;
 
@@ -298,17 +1209,22 @@ where
             can_go_back: state.history.len() > 1,
         };
 
-        self.children
+        let tab_index = self.tab_index(key);
+        let tab_bar_order = self.tab_bar_order();
+        let tabs_element = match self.tabs_builder.as_ref() {
+            Some(builder) => Some(builder(params.clone(), &self.tabs())),
+            None => self.tabs_cache.take_element(),
+        }
+        .inspect(|element| {
+            children[children_len - 1].diff(element);
+            tab_bar::set_active(&mut children[children_len - 1], tab_index);
+            tab_bar::set_order(&mut children[children_len - 1], tab_bar_order.clone());
+        });
+
+        let node = self
+            .children
             .get_mut(&disc)
             .map(|page| {
-                let tabs_element = self.tabs_builder.as_ref().map(|builder| {
-                    let element = builder(params.clone(), &self.pages);
-
-                    children[children_len - 1].diff(&element);
-
-                    element
-                });
-
                 if page.is_empty() {
                     page.update_cache(params);
                 }
@@ -324,7 +1240,7 @@ where
                 children.swap(page_index, children_len - 2);
 
                 let mut node = layout::flex::resolve(
-                    layout::flex::Axis::Vertical,
+                    self.mode.axis(),
                     renderer,
                     &limits,
                     self.width,
@@ -342,15 +1258,31 @@ where
                     self.tabs_cache.return_element(items.remove(0));
                 }
 
-                if let Mode::Top = self.mode {
+                if node.children().len() < 2 {
+                    node = Node::with_children(
+                        node.size(),
+                        node.children()
+                            .iter()
+                            .cloned()
+                            .chain([Node::new(Size::ZERO)])
+                            .collect(),
+                    );
+                } else if self.mode.leads() {
                     let mut children = node.children().to_vec();
 
                     let header_position =
                         Point::new(children[0].bounds().x, children[0].bounds().y);
-                    let page_position = Point::new(
-                        children[0].bounds().x,
-                        children[0].bounds().y + children[1].bounds().height,
-                    );
+                    let page_position = if self.mode.is_vertical() {
+                        Point::new(
+                            children[0].bounds().x + children[1].bounds().width,
+                            children[0].bounds().y,
+                        )
+                    } else {
+                        Point::new(
+                            children[0].bounds().x,
+                            children[0].bounds().y + children[1].bounds().height,
+                        )
+                    };
 
                     children[0].move_to_mut(page_position);
                     children[1].move_to_mut(header_position);
@@ -362,7 +1294,40 @@ where
 
                 node
             })
-            .unwrap()
+            .unwrap();
+
+        let bounds = node.children()[0].bounds();
+        let can_go_back = state.history.len() > 1;
+
+        let extra_pages: Vec<Key> = if state.drag.as_ref().is_some_and(|drag| drag.dragging) {
+            self.mounted_neighbors(state, key)
+                .into_iter()
+                .flatten()
+                .collect()
+        } else {
+            state
+                .outgoing
+                .iter()
+                .filter(|_| state.frame.is_some())
+                .cloned()
+                .collect()
+        };
+
+        let extra_nodes: Vec<_> = extra_pages
+            .iter()
+            .filter_map(|key| self.layout_page(key, can_go_back, bounds, children, renderer))
+            .collect();
+
+        state.mounted.insert(disc);
+
+        if extra_nodes.is_empty() {
+            return node;
+        }
+
+        Node::with_children(
+            node.size(),
+            node.children().iter().cloned().chain(extra_nodes).collect(),
+        )
     }
 
     fn update(
@@ -385,6 +1350,46 @@ where
             shell.invalidate_layout();
             shell.request_redraw();
 
+            if let Some(frame) = state.frame.as_mut() {
+                frame.update();
+            }
+
+            if state.reordered {
+                state.reordered = false;
+
+                if let Some(on_tabs_reordered) = self.on_tabs_reordered.as_ref() {
+                    shell.publish(on_tabs_reordered(self.ordered_pages(state)));
+                }
+
+                return;
+            }
+
+            if let Some(closing) = state.closing.take() {
+                if let Some(on_tab_close) = self.on_tab_close.as_ref() {
+                    shell.publish(on_tab_close(closing));
+                }
+
+                return;
+            }
+
+            if let Some(rejected) = state.rejected.take() {
+                if let Some(on_navigation_rejected) = self.on_navigation_rejected.as_ref() {
+                    shell.publish(on_navigation_rejected(rejected));
+                }
+
+                return;
+            }
+
+            if let Some(reselected) = state.reselected.take() {
+                self.apply_reselect(&reselected, &mut tree.children, layout.child(0), renderer);
+
+                if let Some(on_tab_reselect) = self.on_tab_reselect.as_ref() {
+                    shell.publish(on_tab_reselect(reselected));
+                }
+
+                return;
+            }
+
             if let Some(on_navigation_end) = self.on_navigation_end.as_ref() {
                 shell.publish(on_navigation_end(
                     state.get_previous_key().cloned(),
@@ -394,11 +1399,47 @@ where
             return;
         }
 
+        if let Event::Window(window::Event::RedrawRequested(_)) = event
+            && let Some(frame) = state.frame.as_mut()
+        {
+            if frame.is_complete() {
+                state.frame = None;
+                state.outgoing = None;
+                shell.invalidate_layout();
+            } else {
+                frame.update();
+            }
+
+            shell.request_redraw();
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event
+            && state.frame.is_none()
+            && state.drag.is_none()
+        {
+            let current = self.slot(state.history.last().unwrap());
+
+            for (index, disc) in self.children.keys().enumerate() {
+                if *disc != current
+                    && self.unmount_on_blur.contains(disc)
+                    && state.mounted.remove(disc)
+                {
+                    tree.children[index] = Tree::empty();
+                }
+            }
+        }
+
         let children_len = tree.children.len();
+        let children_layout: Vec<_> = layout.children().collect();
+
+        if self.pager && self.update_drag(state, event, children_layout[0].bounds(), cursor, shell)
+        {
+            return;
+        }
+
         let key = state.history.last().unwrap();
-        let disc = std::mem::discriminant(key);
+        let disc = self.slot(key);
         let page_index = self.children.get_index_of(&disc).unwrap();
-        let children_layout: Vec<_> = layout.children().collect();
 
         if let Some(page) = self.children.get_mut(&disc) {
             let element = page.get_element_mut().unwrap();
@@ -415,6 +1456,22 @@ where
             );
         }
 
+        if let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event
+            && !shell.is_event_captured()
+            && let Some(target) = self
+                .key_binding
+                .as_ref()
+                .and_then(|key_binding| key_binding(key, *modifiers))
+                .and_then(|shortcut| self.shortcut_target(state, shortcut))
+        {
+            state.navigate(target);
+            state.request_update();
+            shell.capture_event();
+            shell.invalidate_layout();
+            shell.request_redraw();
+            return;
+        }
+
         if let Some(tabs) = self.tabs_cache.get_element_mut() {
             tabs.as_widget_mut().update(
                 &mut tree.children[children_len - 1],
@@ -427,6 +1484,39 @@ where
                 viewport,
             );
         }
+
+        if let Some(selected) = tab_bar::take_selection(&mut tree.children[children_len - 1])
+            .and_then(|index| self.tab_keys.get(index))
+        {
+            let state = tree.state.downcast_mut::<State<Key>>();
+
+            state.navigate(selected.clone());
+            state.request_update();
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        if let Some(closing) = tab_bar::take_close(&mut tree.children[children_len - 1])
+            .and_then(|index| self.tab_keys.get(index))
+            && let Some(on_tab_close) = self.on_tab_close.as_ref()
+        {
+            shell.publish(on_tab_close(closing.clone()));
+        }
+
+        if let Some(pages) = tab_bar::take_move(&mut tree.children[children_len - 1])
+            .and_then(|(item, slot)| self.move_tab(item, slot))
+        {
+            let state = tree.state.downcast_mut::<State<Key>>();
+
+            state.order = pages.iter().map(|key| self.slot(key)).collect();
+
+            if let Some(on_tabs_reordered) = self.on_tabs_reordered.as_ref() {
+                shell.publish(on_tabs_reordered(pages));
+            }
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
     }
 
     fn mouse_interaction(
@@ -438,16 +1528,33 @@ where
         renderer: &Renderer,
     ) -> mouse::Interaction {
         let state = tree.state.downcast_ref::<State<Key>>();
+
+        if state.drag.as_ref().is_some_and(|drag| drag.dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
         let children_len = tree.children.len();
         let key = state.history.last().unwrap();
-        let disc = std::mem::discriminant(key);
+        let disc = self.slot(key);
         let page_index = self.children.get_index_of(&disc).unwrap();
         let children_layout: Vec<_> = layout.children().collect();
 
-        let interaction = self
-            .children
-            .get(&disc)
-            .map(|page| {
+        let tabs = self
+            .tabs_cache
+            .get_element()
+            .filter(|_| cursor.is_over(children_layout[1].bounds()))
+            .map(|tabs| {
+                tabs.as_widget().mouse_interaction(
+                    &tree.children[children_len - 1],
+                    children_layout[1],
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            });
+
+        let interaction = tabs.or_else(|| {
+            self.children.get(&disc).map(|page| {
                 let element = page.get_element().unwrap();
 
                 element.as_widget().mouse_interaction(
@@ -458,17 +1565,7 @@ where
                     renderer,
                 )
             })
-            .or_else(|| {
-                self.tabs_cache.get_element().map(|tabs| {
-                    tabs.as_widget().mouse_interaction(
-                        &tree.children[children_len - 1],
-                        children_layout[1],
-                        cursor,
-                        viewport,
-                        renderer,
-                    )
-                })
-            });
+        });
 
         interaction.unwrap_or_default()
     }
@@ -487,22 +1584,135 @@ where
             let state = tree.state.downcast_ref::<State<Key>>();
             let children_len = tree.children.len();
             let key = state.history.last().unwrap();
-            let disc = std::mem::discriminant(key);
+            let disc = self.slot(key);
             let page_index = self.children.get_index_of(&disc).unwrap();
             let children_layout: Vec<_> = layout.children().collect();
 
+            let extra_page = |key: &Key, index: usize| {
+                let disc = self.slot(key);
+                let tree_index = self.children.get_index_of(&disc)?;
+                let element = self.children.get(&disc)?.get_element()?;
+
+                Some((
+                    element,
+                    &tree.children[tree_index],
+                    *children_layout.get(2 + index)?,
+                ))
+            };
+
             if let Some(page) = self.children.get(&disc) {
                 let element = page.get_element().unwrap();
-
-                element.as_widget().draw(
-                    &tree.children[page_index],
-                    renderer,
-                    theme,
-                    style,
-                    children_layout[0],
-                    cursor,
-                    &clipped_viewport,
-                );
+                let current = (element, &tree.children[page_index], children_layout[0]);
+                let bounds = children_layout[0].bounds();
+                let layer = bounds.intersection(&clipped_viewport).unwrap_or(bounds);
+
+                if let Some(drag) = state.drag.as_ref().filter(|drag| drag.dragging) {
+                    let [previous, next] = self.mounted_neighbors(state, key);
+                    let toward_next = self.get_direction().mirror(drag.offset) < 0.0;
+
+                    let neighbor = if toward_next {
+                        next.as_ref()
+                            .and_then(|next| extra_page(next, usize::from(previous.is_some())))
+                    } else {
+                        previous
+                            .as_ref()
+                            .and_then(|previous| extra_page(previous, 0))
+                    };
+
+                    renderer.with_layer(layer, |renderer| {
+                        if let Some(neighbor) = neighbor {
+                            draw_page(
+                                neighbor,
+                                drag.offset - drag.offset.signum() * bounds.width,
+                                renderer,
+                                theme,
+                                style,
+                                cursor,
+                                &clipped_viewport,
+                            );
+                        }
+
+                        draw_page(
+                            current,
+                            drag.offset,
+                            renderer,
+                            theme,
+                            style,
+                            cursor,
+                            &clipped_viewport,
+                        );
+                    });
+                } else if let Some((outgoing, progress)) =
+                    state.outgoing.as_ref().zip(state.progress())
+                    && let Some(outgoing_page) = extra_page(outgoing, 0)
+                {
+                    renderer.with_layer(layer, |renderer| {
+                        if self.pager || state.transition == TabTransition::Slide {
+                            let forward = self.page_position(key) >= self.page_position(outgoing);
+                            let offset = self.get_direction().mirror(if forward {
+                                bounds.width
+                            } else {
+                                -bounds.width
+                            });
+
+                            draw_page(
+                                outgoing_page,
+                                -offset * progress,
+                                renderer,
+                                theme,
+                                style,
+                                cursor,
+                                &clipped_viewport,
+                            );
+
+                            draw_page(
+                                current,
+                                offset * (1.0 - progress),
+                                renderer,
+                                theme,
+                                style,
+                                cursor,
+                                &clipped_viewport,
+                            );
+                        } else {
+                            let (visible, cover) = if progress < 0.5 {
+                                (outgoing_page, progress * 2.0)
+                            } else {
+                                (current, (1.0 - progress) * 2.0)
+                            };
+
+                            draw_page(
+                                visible,
+                                0.0,
+                                renderer,
+                                theme,
+                                style,
+                                cursor,
+                                &clipped_viewport,
+                            );
+
+                            renderer.with_layer(bounds, |renderer| {
+                                renderer.fill_quad(
+                                    Quad {
+                                        bounds,
+                                        ..Default::default()
+                                    },
+                                    theme.palette().background.scale_alpha(cover),
+                                );
+                            });
+                        }
+                    });
+                } else {
+                    element.as_widget().draw(
+                        current.1,
+                        renderer,
+                        theme,
+                        style,
+                        current.2,
+                        cursor,
+                        &clipped_viewport,
+                    );
+                }
             }
 
             if let Some(tabs) = self.tabs_cache.get_element() {
@@ -535,7 +1745,7 @@ where
             }
 
             let key = state.history.last().unwrap();
-            let disc = std::mem::discriminant(key);
+            let disc = self.slot(key);
             let page_index = self.children.get_index_of(&disc).unwrap();
             let children_layout: Vec<_> = layout.children().collect();
 
@@ -583,6 +1793,24 @@ where
     }
 }
 
+fn draw_page<Message, Renderer>(
+    (element, tree, layout): PageDraw<'_, '_, Message, Renderer>,
+    translation: f32,
+    renderer: &mut Renderer,
+    theme: &Theme,
+    style: &renderer::Style,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+) where
+    Renderer: iced_core::Renderer,
+{
+    renderer.with_translation(Vector::new(translation, 0.0), |renderer| {
+        element
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    });
+}
+
 impl<'a, Key, Message, Renderer> From<TabsNavigator<'a, Key, Message, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -604,3 +1832,78 @@ where
 {
     TabsNavigator::new(home_page)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::testing::{self, Page};
+    use iced_widget::Space;
+
+    fn tabs(pages: &[Page]) -> Element<'static, (), Theme, ()> {
+        pages
+            .iter()
+            .fold(TabsNavigator::new(Page::Home), |tabs, page| {
+                tabs.insert_page(*page, Space::new())
+            })
+            .into()
+    }
+
+    #[test]
+    fn reconcile_keeps_page_state_by_key() {
+        let mut tree = testing::mount(tabs(&[Page::Home, Page::List, Page::Settings]));
+
+        tree.children[2].state = tree::State::new(7_usize);
+        tree.diff(tabs(&[Page::Home, Page::Settings]));
+
+        assert_eq!(tree.children.len(), 3);
+        assert_eq!(tree.children[1].state.downcast_ref::<usize>(), &7);
+    }
+
+    #[test]
+    fn reconcile_activates_previous_tab_when_active_page_is_removed() {
+        let mut tree = testing::mount(tabs(&[Page::Home, Page::List, Page::Settings]));
+
+        testing::state::<State<Page>>(&mut tree).0.history = vec![Page::Settings, Page::List];
+        tree.diff(tabs(&[Page::Home, Page::Settings]));
+
+        let (state, _) = testing::state::<State<Page>>(&mut tree);
+
+        assert_eq!(state.history, vec![Page::Settings]);
+        assert_eq!(state.previous_page, Some(Page::List));
+        assert!(state.pending_update);
+    }
+
+    #[test]
+    fn reconcile_falls_back_to_first_page_when_home_is_removed() {
+        let mut tree = testing::mount(tabs(&[Page::Home, Page::List, Page::Settings]));
+
+        tree.diff(tabs(&[Page::List, Page::Settings]));
+
+        let (state, _) = testing::state::<State<Page>>(&mut tree);
+
+        assert_eq!(state.history, vec![Page::List]);
+    }
+
+    #[test]
+    fn navigate_ignores_keys_without_a_page() {
+        let mut tree = testing::mount(tabs(&[Page::Home]));
+        let (state, _) = testing::state::<State<Page>>(&mut tree);
+
+        state.navigate(Page::List);
+
+        assert_eq!(state.history, vec![Page::Home]);
+        assert!(state.frame.is_none());
+    }
+
+    #[test]
+    fn tab_options_resolve_documents_inserted_later() {
+        let mut tabs: TabsNavigator<'static, Page, (), ()> = TabsNavigator::new(Page::Home)
+            .insert_page(Page::Home, Space::new())
+            .tab_options(Page::List, TabOptions::new("List"))
+            .insert_document(Page::List, Space::new());
+
+        tabs.mount_tab_bar();
+
+        assert_eq!(tabs.tab_keys, vec![Page::List]);
+    }
+}